@@ -0,0 +1,141 @@
+use log::error;
+use pixels::{Pixels, SurfaceTexture};
+use pixels_primitives::{Animation, AnimationMode, BlitOptions, FillRule, Image, SpriteSheet};
+use std::error::Error;
+use std::time::{Duration, Instant};
+use winit::dpi::LogicalSize;
+use winit::event::Event;
+use winit::event_loop::{ControlFlow, EventLoop};
+use winit::window::WindowBuilder;
+use winit_input_helper::WinitInputHelper;
+
+const WIDTH: i32 = 800;
+const HEIGHT: i32 = 800;
+const FRAME_SIZE: u32 = 96;
+const FRAMES: usize = 8;
+
+fn main() -> Result<(), Box<dyn Error>> {
+    let event_loop = EventLoop::new();
+    let mut input = WinitInputHelper::new();
+    let window = {
+        let size = LogicalSize::new(WIDTH as f64, HEIGHT as f64);
+        WindowBuilder::new()
+            .with_title("Animation Example")
+            .with_inner_size(size)
+            .with_min_inner_size(size)
+            .build(&event_loop)
+            .unwrap()
+    };
+
+    let mut pixels = {
+        let window_size = window.inner_size();
+        let surface_texture = SurfaceTexture::new(window_size.width, window_size.height, &window);
+        Pixels::new(WIDTH as u32, HEIGHT as u32, surface_texture)?
+    };
+
+    let sheet = make_sheet();
+    let spin = Animation::new(
+        (0..FRAMES).collect(),
+        Duration::from_millis(80),
+        AnimationMode::Loop,
+    );
+    let swing = Animation::new(
+        (0..FRAMES).collect(),
+        Duration::from_millis(120),
+        AnimationMode::PingPong,
+    );
+    let once = Animation::new(
+        (0..FRAMES).collect(),
+        Duration::from_millis(250),
+        AnimationMode::Once,
+    );
+    let started = Instant::now();
+
+    event_loop.run(move |event, _, control_flow| {
+        // Draw the current frame
+        if let Event::RedrawRequested(_) = event {
+            let elapsed = started.elapsed();
+            let frame = pixels.get_frame();
+            pixels_primitives::rect_filled(
+                frame,
+                WIDTH,
+                0,
+                0,
+                WIDTH - 1,
+                HEIGHT - 1,
+                &[30, 30, 40, 255],
+            );
+
+            let options = BlitOptions::default();
+            spin.draw(&sheet, frame, WIDTH, elapsed, 150, 350, &options);
+            swing.draw(&sheet, frame, WIDTH, elapsed, 350, 350, &options);
+            once.draw(&sheet, frame, WIDTH, elapsed, 550, 350, &options);
+
+            // Mirroring the frames makes the bar spin the other way, without drawing any more frames.
+            let mirrored = BlitOptions {
+                flip_x: true,
+                ..options
+            };
+            spin.draw(&sheet, frame, WIDTH, elapsed, 150, 500, &mirrored);
+
+            if pixels
+                .render()
+                .map_err(|e| error!("pixels.render() failed: {}", e))
+                .is_err()
+            {
+                *control_flow = ControlFlow::Exit;
+                return;
+            }
+        }
+
+        // Handle input events
+        if input.update(&event) {
+            if input.quit() {
+                *control_flow = ControlFlow::Exit;
+                return;
+            }
+
+            window.request_redraw();
+        }
+    });
+}
+
+/// Draws a sheet of frames of a spinning bar, side by side.
+fn make_sheet() -> SpriteSheet {
+    let mut image = Image::new(FRAME_SIZE * FRAMES as u32, FRAME_SIZE);
+    let width = image.width() as i32;
+    let center = FRAME_SIZE as f64 / 2.0;
+
+    for frame in 0..FRAMES {
+        let angle = frame as f64 / FRAMES as f64 * std::f64::consts::PI;
+        let (sin, cos) = angle.sin_cos();
+        let offset = (frame as u32 * FRAME_SIZE) as f64;
+        let bar = [
+            (
+                offset + center - cos * 40.0 - sin * 8.0,
+                center - sin * 40.0 + cos * 8.0,
+            ),
+            (
+                offset + center + cos * 40.0 - sin * 8.0,
+                center + sin * 40.0 + cos * 8.0,
+            ),
+            (
+                offset + center + cos * 40.0 + sin * 8.0,
+                center + sin * 40.0 - cos * 8.0,
+            ),
+            (
+                offset + center - cos * 40.0 + sin * 8.0,
+                center - sin * 40.0 - cos * 8.0,
+            ),
+        ];
+        pixels_primitives::polygon_filled(
+            image.pixels_mut(),
+            width,
+            &bar,
+            FillRule::NonZero,
+            &[255, 200, 0, 255],
+        );
+    }
+
+    SpriteSheet::from_grid(image, FRAME_SIZE, FRAME_SIZE)
+}
@@ -0,0 +1,108 @@
+use log::error;
+use pixels::{Pixels, SurfaceTexture};
+use std::error::Error;
+use winit::dpi::LogicalSize;
+use winit::event::Event;
+use winit::event_loop::{ControlFlow, EventLoop};
+use winit::window::WindowBuilder;
+use winit_input_helper::WinitInputHelper;
+
+const WIDTH: i32 = 800;
+const HEIGHT: i32 = 800;
+
+fn main() -> Result<(), Box<dyn Error>> {
+    let event_loop = EventLoop::new();
+    let mut input = WinitInputHelper::new();
+    let window = {
+        let size = LogicalSize::new(WIDTH as f64, HEIGHT as f64);
+        WindowBuilder::new()
+            .with_title("Curves Example")
+            .with_inner_size(size)
+            .with_min_inner_size(size)
+            .build(&event_loop)
+            .unwrap()
+    };
+
+    let mut pixels = {
+        let window_size = window.inner_size();
+        let surface_texture = SurfaceTexture::new(window_size.width, window_size.height, &window);
+        Pixels::new(WIDTH as u32, HEIGHT as u32, surface_texture)?
+    };
+
+    // Node-graph style wires between a column of outputs and a column of inputs.
+    for i in 0..6 {
+        let starting_y = 100.0 + i as f64 * 100.0;
+        let ending_y = 700.0 - i as f64 * 100.0;
+        pixels_primitives::cubic_bezier(
+            pixels.get_frame(),
+            WIDTH,
+            100.0,
+            starting_y,
+            400.0,
+            starting_y,
+            400.0,
+            ending_y,
+            700.0,
+            ending_y,
+            0.25,
+            &[255, 255, 255, 255],
+        );
+    }
+
+    pixels_primitives::quad_bezier(
+        pixels.get_frame(),
+        WIDTH,
+        100.0,
+        750.0,
+        400.0,
+        450.0,
+        700.0,
+        750.0,
+        0.25,
+        &[255, 0, 0, 255],
+    );
+
+    // Noisy samples, smoothed through every point and smoothed towards the points.
+    let samples = [
+        (100.0, 250.0),
+        (200.0, 180.0),
+        (300.0, 320.0),
+        (400.0, 220.0),
+        (500.0, 300.0),
+        (600.0, 150.0),
+        (700.0, 260.0),
+    ];
+    pixels_primitives::catmull_rom(
+        pixels.get_frame(),
+        WIDTH,
+        &samples,
+        0.0,
+        0.25,
+        &[0, 255, 0, 255],
+    );
+    pixels_primitives::b_spline(pixels.get_frame(), WIDTH, &samples, 0.25, &[0, 0, 255, 255]);
+
+    event_loop.run(move |event, _, control_flow| {
+        // Draw the current frame
+        if let Event::RedrawRequested(_) = event {
+            if pixels
+                .render()
+                .map_err(|e| error!("pixels.render() failed: {}", e))
+                .is_err()
+            {
+                *control_flow = ControlFlow::Exit;
+                return;
+            }
+        }
+
+        // Handle input events
+        if input.update(&event) {
+            if input.quit() {
+                *control_flow = ControlFlow::Exit;
+                return;
+            }
+
+            window.request_redraw();
+        }
+    });
+}
@@ -0,0 +1,125 @@
+use log::error;
+use pixels::{Pixels, SurfaceTexture};
+use pixels_primitives::{ColorStop, ConicGradient, LinearGradient, RadialGradient, SpreadMode};
+use std::error::Error;
+use winit::dpi::LogicalSize;
+use winit::event::Event;
+use winit::event_loop::{ControlFlow, EventLoop};
+use winit::window::WindowBuilder;
+use winit_input_helper::WinitInputHelper;
+
+const WIDTH: i32 = 800;
+const HEIGHT: i32 = 800;
+
+fn main() -> Result<(), Box<dyn Error>> {
+    let event_loop = EventLoop::new();
+    let mut input = WinitInputHelper::new();
+    let window = {
+        let size = LogicalSize::new(WIDTH as f64, HEIGHT as f64);
+        WindowBuilder::new()
+            .with_title("Gradients Example")
+            .with_inner_size(size)
+            .with_min_inner_size(size)
+            .build(&event_loop)
+            .unwrap()
+    };
+
+    let mut pixels = {
+        let window_size = window.inner_size();
+        let surface_texture = SurfaceTexture::new(window_size.width, window_size.height, &window);
+        Pixels::new(WIDTH as u32, HEIGHT as u32, surface_texture)?
+    };
+
+    let sunset = LinearGradient::new(
+        (0.0, 0.0),
+        (0.0, 400.0),
+        vec![
+            ColorStop::new(0.0, [40, 20, 90, 255]),
+            ColorStop::new(0.6, [230, 90, 60, 255]),
+            ColorStop::new(1.0, [255, 200, 80, 255]),
+        ],
+        SpreadMode::Pad,
+    );
+    pixels_primitives::rect_filled(pixels.get_frame(), WIDTH, 0, 0, WIDTH - 1, 400, &sunset);
+
+    let stripes = LinearGradient::new(
+        (100.0, 500.0),
+        (140.0, 540.0),
+        vec![
+            ColorStop::new(0.0, [255, 255, 255, 255]),
+            ColorStop::new(1.0, [0, 128, 255, 255]),
+        ],
+        SpreadMode::Reflect,
+    );
+    pixels_primitives::circle_filled(pixels.get_frame(), WIDTH, 200.0, 600.0, 150.0, &stripes);
+
+    let fade = LinearGradient::new(
+        (450.0, 0.0),
+        (750.0, 0.0),
+        vec![
+            ColorStop::new(0.0, [0, 255, 0, 255]),
+            ColorStop::new(1.0, [0, 60, 0, 255]),
+        ],
+        SpreadMode::Pad,
+    );
+    pixels_primitives::triangle_filled(
+        pixels.get_frame(),
+        WIDTH,
+        450,
+        750,
+        600,
+        450,
+        750,
+        750,
+        &fade,
+    );
+
+    // A sun with the light coming from its top left.
+    let sun = RadialGradient::new(
+        (600.0, 200.0),
+        120.0,
+        (560.0, 160.0),
+        vec![
+            ColorStop::new(0.0, [255, 255, 230, 255]),
+            ColorStop::new(1.0, [255, 140, 0, 255]),
+        ],
+        SpreadMode::Pad,
+    );
+    pixels_primitives::circle_filled(pixels.get_frame(), WIDTH, 600.0, 200.0, 120.0, &sun);
+
+    let color_wheel = ConicGradient::new(
+        (150.0, 150.0),
+        0.0,
+        vec![
+            ColorStop::new(0.0, [255, 0, 0, 255]),
+            ColorStop::new(1.0 / 3.0, [0, 255, 0, 255]),
+            ColorStop::new(2.0 / 3.0, [0, 0, 255, 255]),
+            ColorStop::new(1.0, [255, 0, 0, 255]),
+        ],
+    );
+    pixels_primitives::circle_filled(pixels.get_frame(), WIDTH, 150.0, 150.0, 100.0, &color_wheel);
+
+    event_loop.run(move |event, _, control_flow| {
+        // Draw the current frame
+        if let Event::RedrawRequested(_) = event {
+            if pixels
+                .render()
+                .map_err(|e| error!("pixels.render() failed: {}", e))
+                .is_err()
+            {
+                *control_flow = ControlFlow::Exit;
+                return;
+            }
+        }
+
+        // Handle input events
+        if input.update(&event) {
+            if input.quit() {
+                *control_flow = ControlFlow::Exit;
+                return;
+            }
+
+            window.request_redraw();
+        }
+    });
+}
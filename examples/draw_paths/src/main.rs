@@ -0,0 +1,130 @@
+use log::error;
+use pixels::{Pixels, SurfaceTexture};
+use pixels_primitives::{FillRule, LineCap, LineJoin, Path, StrokeStyle};
+use std::error::Error;
+use winit::dpi::LogicalSize;
+use winit::event::Event;
+use winit::event_loop::{ControlFlow, EventLoop};
+use winit::window::WindowBuilder;
+use winit_input_helper::WinitInputHelper;
+
+const WIDTH: i32 = 800;
+const HEIGHT: i32 = 800;
+
+fn main() -> Result<(), Box<dyn Error>> {
+    let event_loop = EventLoop::new();
+    let mut input = WinitInputHelper::new();
+    let window = {
+        let size = LogicalSize::new(WIDTH as f64, HEIGHT as f64);
+        WindowBuilder::new()
+            .with_title("Paths Example")
+            .with_inner_size(size)
+            .with_min_inner_size(size)
+            .build(&event_loop)
+            .unwrap()
+    };
+
+    let mut pixels = {
+        let window_size = window.inner_size();
+        let surface_texture = SurfaceTexture::new(window_size.width, window_size.height, &window);
+        Pixels::new(WIDTH as u32, HEIGHT as u32, surface_texture)?
+    };
+
+    // A heart, filled and then outlined with rounded corners.
+    let mut heart = Path::new();
+    heart
+        .move_to(200.0, 350.0)
+        .cubic_to(50.0, 250.0, 100.0, 100.0, 200.0, 170.0)
+        .cubic_to(300.0, 100.0, 350.0, 250.0, 200.0, 350.0)
+        .close();
+    heart.fill(
+        pixels.get_frame(),
+        WIDTH,
+        FillRule::NonZero,
+        &[255, 0, 0, 255],
+    );
+    heart.stroke(
+        pixels.get_frame(),
+        WIDTH,
+        &StrokeStyle {
+            width: 6.0,
+            join: LineJoin::Round,
+            ..StrokeStyle::default()
+        },
+        &[255, 255, 255, 255],
+    );
+
+    // A pill shape made out of two arcs.
+    let mut pill = Path::new();
+    pill.move_to(500.0, 150.0)
+        .line_to(650.0, 150.0)
+        .arc_to(50.0, 50.0, 0.0, false, true, 650.0, 250.0)
+        .line_to(500.0, 250.0)
+        .arc_to(50.0, 50.0, 0.0, false, true, 500.0, 150.0)
+        .close();
+    pill.fill(
+        pixels.get_frame(),
+        WIDTH,
+        FillRule::NonZero,
+        &[0, 0, 255, 255],
+    );
+
+    // An open zigzag showing the different caps.
+    for (i, cap) in [LineCap::Butt, LineCap::Round, LineCap::Square]
+        .into_iter()
+        .enumerate()
+    {
+        let y = 450.0 + i as f64 * 100.0;
+        let mut zigzag = Path::new();
+        zigzag
+            .move_to(100.0, y)
+            .line_to(250.0, y + 50.0)
+            .line_to(400.0, y)
+            .quad_to(550.0, y - 50.0, 700.0, y + 50.0);
+        zigzag.stroke(
+            pixels.get_frame(),
+            WIDTH,
+            &StrokeStyle {
+                width: 20.0,
+                cap,
+                ..StrokeStyle::default()
+            },
+            &[0, 255, 0, 255],
+        );
+    }
+
+    // An icon taken straight from the `d` attribute of an SVG file.
+    let icon: Path = "M 420 10 l 22 45 49 7 -35 35 8 49 -44 -23 -44 23 8 -49 -35 -35 49 -7 z"
+        .parse()
+        .expect("the icon path data is valid");
+    icon.fill(
+        pixels.get_frame(),
+        WIDTH,
+        FillRule::NonZero,
+        &[255, 255, 0, 255],
+    );
+
+    event_loop.run(move |event, _, control_flow| {
+        // Draw the current frame
+        if let Event::RedrawRequested(_) = event {
+            if pixels
+                .render()
+                .map_err(|e| error!("pixels.render() failed: {}", e))
+                .is_err()
+            {
+                *control_flow = ControlFlow::Exit;
+                return;
+            }
+        }
+
+        // Handle input events
+        if input.update(&event) {
+            if input.quit() {
+                *control_flow = ControlFlow::Exit;
+                return;
+            }
+
+            window.request_redraw();
+        }
+    });
+}
@@ -0,0 +1,134 @@
+use log::error;
+use pixels::{Pixels, SurfaceTexture};
+use pixels_primitives::{FillRule, Hatch, HatchStyle, Image, ImagePattern, RepeatMode, Stipple};
+use std::error::Error;
+use winit::dpi::LogicalSize;
+use winit::event::Event;
+use winit::event_loop::{ControlFlow, EventLoop};
+use winit::window::WindowBuilder;
+use winit_input_helper::WinitInputHelper;
+
+const WIDTH: i32 = 800;
+const HEIGHT: i32 = 800;
+
+fn main() -> Result<(), Box<dyn Error>> {
+    let event_loop = EventLoop::new();
+    let mut input = WinitInputHelper::new();
+    let window = {
+        let size = LogicalSize::new(WIDTH as f64, HEIGHT as f64);
+        WindowBuilder::new()
+            .with_title("Patterns Example")
+            .with_inner_size(size)
+            .with_min_inner_size(size)
+            .build(&event_loop)
+            .unwrap()
+    };
+
+    let mut pixels = {
+        let window_size = window.inner_size();
+        let surface_texture = SurfaceTexture::new(window_size.width, window_size.height, &window);
+        Pixels::new(WIDTH as u32, HEIGHT as u32, surface_texture)?
+    };
+
+    // A tiny checkerboard, scaled up so every pixel of it covers 16x16 pixels of the frame.
+    let tile = Image::from_rgba(
+        2,
+        2,
+        vec![
+            70, 160, 60, 255, 50, 130, 40, 255, //
+            50, 130, 40, 255, 70, 160, 60, 255,
+        ],
+    );
+    let grass = ImagePattern::new(&tile, (0.0, 0.0), 16.0, RepeatMode::Repeat);
+    pixels_primitives::polygon_filled(
+        pixels.get_frame(),
+        WIDTH,
+        &[
+            (50.0, 80.0),
+            (350.0, 40.0),
+            (380.0, 300.0),
+            (200.0, 360.0),
+            (80.0, 250.0),
+        ],
+        FillRule::NonZero,
+        &grass,
+    );
+
+    // The same tile, only repeated along a single row.
+    let border = ImagePattern::new(&tile, (450.0, 100.0), 16.0, RepeatMode::RepeatX);
+    pixels_primitives::rect_filled(pixels.get_frame(), WIDTH, 450, 50, 750, 200, &border);
+
+    // Hatches and stipples tell regions apart without relying on color.
+    let styles = [
+        HatchStyle::Horizontal,
+        HatchStyle::Vertical,
+        HatchStyle::ForwardDiagonal,
+        HatchStyle::BackwardDiagonal,
+        HatchStyle::Cross,
+        HatchStyle::DiagonalCross,
+    ];
+    for (i, style) in styles.into_iter().enumerate() {
+        let x = 50 + i as i32 * 120;
+        let hatch = Hatch::new(style, 10.0, 0.0, 2.0, [255, 255, 255, 255], None);
+        pixels_primitives::rect_filled(pixels.get_frame(), WIDTH, x, 450, x + 100, 550, &hatch);
+    }
+
+    let rotated = Hatch::new(
+        HatchStyle::Cross,
+        12.0,
+        std::f64::consts::FRAC_PI_6,
+        1.0,
+        [0, 0, 0, 255],
+        Some([200, 200, 255, 255]),
+    );
+    pixels_primitives::circle_filled(pixels.get_frame(), WIDTH, 200.0, 680.0, 90.0, &rotated);
+
+    let gray = Stipple::checkerboard([255, 255, 255, 255], None);
+    pixels_primitives::star_filled(
+        pixels.get_frame(),
+        WIDTH,
+        450.0,
+        680.0,
+        100.0,
+        40.0,
+        5,
+        0.0,
+        &gray,
+    );
+
+    let dots = Stipple::sparse_dots([255, 255, 0, 255], Some([60, 60, 60, 255]));
+    pixels_primitives::regular_polygon_filled(
+        pixels.get_frame(),
+        WIDTH,
+        670.0,
+        680.0,
+        90.0,
+        6,
+        0.0,
+        &dots,
+    );
+
+    event_loop.run(move |event, _, control_flow| {
+        // Draw the current frame
+        if let Event::RedrawRequested(_) = event {
+            if pixels
+                .render()
+                .map_err(|e| error!("pixels.render() failed: {}", e))
+                .is_err()
+            {
+                *control_flow = ControlFlow::Exit;
+                return;
+            }
+        }
+
+        // Handle input events
+        if input.update(&event) {
+            if input.quit() {
+                *control_flow = ControlFlow::Exit;
+                return;
+            }
+
+            window.request_redraw();
+        }
+    });
+}
@@ -0,0 +1,155 @@
+use log::error;
+use pixels::{Pixels, SurfaceTexture};
+use std::error::Error;
+use winit::dpi::LogicalSize;
+use winit::event::Event;
+use winit::event_loop::{ControlFlow, EventLoop};
+use winit::window::WindowBuilder;
+use winit_input_helper::WinitInputHelper;
+
+const WIDTH: i32 = 800;
+const HEIGHT: i32 = 800;
+
+fn main() -> Result<(), Box<dyn Error>> {
+    let event_loop = EventLoop::new();
+    let mut input = WinitInputHelper::new();
+    let window = {
+        let size = LogicalSize::new(WIDTH as f64, HEIGHT as f64);
+        WindowBuilder::new()
+            .with_title("Polygons Example")
+            .with_inner_size(size)
+            .with_min_inner_size(size)
+            .build(&event_loop)
+            .unwrap()
+    };
+
+    let mut pixels = {
+        let window_size = window.inner_size();
+        let surface_texture = SurfaceTexture::new(window_size.width, window_size.height, &window);
+        Pixels::new(WIDTH as u32, HEIGHT as u32, surface_texture)?
+    };
+
+    // A concave arrow shape.
+    pixels_primitives::polygon_filled(
+        pixels.get_frame(),
+        WIDTH,
+        &[
+            (100.0, 150.0),
+            (300.0, 150.0),
+            (300.0, 80.0),
+            (420.0, 200.0),
+            (300.0, 320.0),
+            (300.0, 250.0),
+            (100.0, 250.0),
+        ],
+        pixels_primitives::FillRule::NonZero,
+        &[255, 255, 255, 255],
+    );
+
+    // A self-intersecting star, with the middle left empty by the even-odd rule.
+    pixels_primitives::polygon_filled(
+        pixels.get_frame(),
+        WIDTH,
+        &[
+            (600.0, 60.0),
+            (690.0, 340.0),
+            (450.0, 170.0),
+            (750.0, 170.0),
+            (510.0, 340.0),
+        ],
+        pixels_primitives::FillRule::EvenOdd,
+        &[255, 0, 0, 255],
+    );
+
+    // The same star, with the middle filled by the non-zero rule.
+    pixels_primitives::polygon_filled(
+        pixels.get_frame(),
+        WIDTH,
+        &[
+            (600.0, 440.0),
+            (690.0, 720.0),
+            (450.0, 550.0),
+            (750.0, 550.0),
+            (510.0, 720.0),
+        ],
+        pixels_primitives::FillRule::NonZero,
+        &[0, 255, 0, 255],
+    );
+
+    // A frame with a window cut out of it, both drawn in a single pass.
+    pixels_primitives::polygon_contours_filled(
+        pixels.get_frame(),
+        WIDTH,
+        &[
+            vec![(60.0, 380.0), (360.0, 380.0), (360.0, 420.0), (60.0, 420.0)],
+            vec![
+                (180.0, 380.0),
+                (180.0, 420.0),
+                (240.0, 420.0),
+                (240.0, 380.0),
+            ],
+        ],
+        pixels_primitives::FillRule::NonZero,
+        &[255, 255, 0, 255],
+    );
+
+    pixels_primitives::polygon(
+        pixels.get_frame(),
+        WIDTH,
+        &[
+            (100.0, 450.0),
+            (350.0, 500.0),
+            (250.0, 600.0),
+            (380.0, 750.0),
+            (80.0, 700.0),
+        ],
+        &[0, 0, 255, 255],
+    );
+
+    pixels_primitives::regular_polygon_filled(
+        pixels.get_frame(),
+        WIDTH,
+        520.0,
+        750.0,
+        40.0,
+        6,
+        0.0,
+        &[0, 255, 255, 255],
+    );
+
+    pixels_primitives::star(
+        pixels.get_frame(),
+        WIDTH,
+        650.0,
+        750.0,
+        40.0,
+        15.0,
+        7,
+        0.0,
+        &[255, 0, 255, 255],
+    );
+
+    event_loop.run(move |event, _, control_flow| {
+        // Draw the current frame
+        if let Event::RedrawRequested(_) = event {
+            if pixels
+                .render()
+                .map_err(|e| error!("pixels.render() failed: {}", e))
+                .is_err()
+            {
+                *control_flow = ControlFlow::Exit;
+                return;
+            }
+        }
+
+        // Handle input events
+        if input.update(&event) {
+            if input.quit() {
+                *control_flow = ControlFlow::Exit;
+                return;
+            }
+
+            window.request_redraw();
+        }
+    });
+}
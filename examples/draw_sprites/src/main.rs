@@ -0,0 +1,195 @@
+use log::error;
+use pixels::{Pixels, SurfaceTexture};
+use pixels_primitives::{BlendMode, BlitOptions, Filter, Image, Region, Rotation, Transform};
+use std::error::Error;
+use winit::dpi::LogicalSize;
+use winit::event::Event;
+use winit::event_loop::{ControlFlow, EventLoop};
+use winit::window::WindowBuilder;
+use winit_input_helper::WinitInputHelper;
+
+const WIDTH: i32 = 800;
+const HEIGHT: i32 = 800;
+
+fn main() -> Result<(), Box<dyn Error>> {
+    let event_loop = EventLoop::new();
+    let mut input = WinitInputHelper::new();
+    let window = {
+        let size = LogicalSize::new(WIDTH as f64, HEIGHT as f64);
+        WindowBuilder::new()
+            .with_title("Sprites Example")
+            .with_inner_size(size)
+            .with_min_inner_size(size)
+            .build(&event_loop)
+            .unwrap()
+    };
+
+    let mut pixels = {
+        let window_size = window.inner_size();
+        let surface_texture = SurfaceTexture::new(window_size.width, window_size.height, &window);
+        Pixels::new(WIDTH as u32, HEIGHT as u32, surface_texture)?
+    };
+
+    let sprite = make_sprite();
+
+    pixels_primitives::rect_filled(
+        pixels.get_frame(),
+        WIDTH,
+        0,
+        0,
+        WIDTH - 1,
+        HEIGHT - 1,
+        &[40, 40, 80, 255],
+    );
+
+    // The magenta background is keyed out, and the half transparent shadow is blended with the frame.
+    let keyed = BlitOptions {
+        color_key: Some([255, 0, 255]),
+        ..BlitOptions::default()
+    };
+    for i in 0..5 {
+        pixels_primitives::blit(
+            pixels.get_frame(),
+            WIDTH,
+            &sprite,
+            100 + i * 120,
+            100,
+            &keyed,
+        );
+    }
+
+    // Mirrored and turned copies, which move whole pixels around without resampling.
+    let orientations = [
+        (true, false, Rotation::Rotate0),
+        (false, true, Rotation::Rotate0),
+        (false, false, Rotation::Rotate90),
+        (false, false, Rotation::Rotate180),
+        (true, false, Rotation::Rotate270),
+    ];
+    for (i, (flip_x, flip_y, rotation)) in orientations.into_iter().enumerate() {
+        pixels_primitives::blit(
+            pixels.get_frame(),
+            WIDTH,
+            &sprite,
+            100 + i as i32 * 120,
+            20,
+            &BlitOptions {
+                flip_x,
+                flip_y,
+                rotation,
+                ..keyed
+            },
+        );
+    }
+
+    // Only the top half of the sprite, drawn without blending.
+    pixels_primitives::blit(
+        pixels.get_frame(),
+        WIDTH,
+        &sprite,
+        100,
+        300,
+        &BlitOptions {
+            source: Some(Region::new(0, 0, 64, 32)),
+            blend: BlendMode::Replace,
+            ..BlitOptions::default()
+        },
+    );
+
+    // Sprites hanging off the edges of the frame are clipped.
+    pixels_primitives::blit(pixels.get_frame(), WIDTH, &sprite, -32, 500, &keyed);
+    pixels_primitives::blit(pixels.get_frame(), WIDTH, &sprite, WIDTH - 32, 500, &keyed);
+
+    // Zoomed in by a whole and by a fractional factor.
+    pixels_primitives::blit_scaled(
+        pixels.get_frame(),
+        WIDTH,
+        &sprite,
+        Region::new(300, 250, 192, 192),
+        Filter::Nearest,
+        &keyed,
+    );
+    pixels_primitives::blit_scaled(
+        pixels.get_frame(),
+        WIDTH,
+        &sprite,
+        Region::new(520, 250, 150, 150),
+        Filter::Bilinear,
+        &keyed,
+    );
+
+    // Rotated around the sprite's center, and skewed.
+    for (i, angle) in [15.0_f64, 45.0, 100.0].iter().enumerate() {
+        let transform = Transform::translate(-32.0, -32.0)
+            .then(&Transform::rotate(angle.to_radians()))
+            .then(&Transform::scale(1.5, 1.5))
+            .then(&Transform::translate(200.0 + i as f64 * 150.0, 600.0));
+        pixels_primitives::blit_transformed(
+            pixels.get_frame(),
+            WIDTH,
+            &sprite,
+            &transform,
+            Filter::Bilinear,
+            &keyed,
+        );
+    }
+    pixels_primitives::blit_transformed(
+        pixels.get_frame(),
+        WIDTH,
+        &sprite,
+        &Transform::skew(0.4, 0.0).then(&Transform::translate(620.0, 560.0)),
+        Filter::Nearest,
+        &keyed,
+    );
+
+    event_loop.run(move |event, _, control_flow| {
+        // Draw the current frame
+        if let Event::RedrawRequested(_) = event {
+            if pixels
+                .render()
+                .map_err(|e| error!("pixels.render() failed: {}", e))
+                .is_err()
+            {
+                *control_flow = ControlFlow::Exit;
+                return;
+            }
+        }
+
+        // Handle input events
+        if input.update(&event) {
+            if input.quit() {
+                *control_flow = ControlFlow::Exit;
+                return;
+            }
+
+            window.request_redraw();
+        }
+    });
+}
+
+/// Draws a 64x64 smiley on a magenta background, with a half transparent shadow.
+fn make_sprite() -> Image {
+    const SIZE: i32 = 64;
+    let mut sprite = Image::new(SIZE as u32, SIZE as u32);
+    let frame = sprite.pixels_mut();
+
+    pixels_primitives::rect_filled(frame, SIZE, 0, 0, SIZE - 1, SIZE - 1, &[255, 0, 255, 255]);
+    pixels_primitives::circle_filled(frame, SIZE, 36.0, 36.0, 26.0, &[0, 0, 0, 128]);
+    pixels_primitives::circle_filled(frame, SIZE, 30.0, 30.0, 26.0, &[255, 220, 0, 255]);
+    pixels_primitives::circle_filled(frame, SIZE, 21.0, 24.0, 4.0, &[0, 0, 0, 255]);
+    pixels_primitives::circle_filled(frame, SIZE, 39.0, 24.0, 4.0, &[0, 0, 0, 255]);
+    pixels_primitives::quad_bezier(
+        frame,
+        SIZE,
+        18.0,
+        38.0,
+        30.0,
+        52.0,
+        42.0,
+        38.0,
+        0.25,
+        &[0, 0, 0, 255],
+    );
+
+    sprite
+}
@@ -0,0 +1,148 @@
+use log::error;
+use pixels::{Pixels, SurfaceTexture};
+use pixels_primitives::{BlitOptions, Image, SpriteSheet, Tilemap};
+use std::error::Error;
+use winit::dpi::LogicalSize;
+use winit::event::Event;
+use winit::event::VirtualKeyCode;
+use winit::event_loop::{ControlFlow, EventLoop};
+use winit::window::WindowBuilder;
+use winit_input_helper::WinitInputHelper;
+
+const WIDTH: i32 = 800;
+const HEIGHT: i32 = 800;
+const TILE: u32 = 32;
+const COLUMNS: u32 = 60;
+const ROWS: u32 = 45;
+const SCROLL_SPEED: i32 = 4;
+
+const GRASS: usize = 0;
+const DIRT: usize = 1;
+const WATER: usize = 2;
+const FLOWER: usize = 3;
+
+fn main() -> Result<(), Box<dyn Error>> {
+    let event_loop = EventLoop::new();
+    let mut input = WinitInputHelper::new();
+    let window = {
+        let size = LogicalSize::new(WIDTH as f64, HEIGHT as f64);
+        WindowBuilder::new()
+            .with_title("Tilemap Example")
+            .with_inner_size(size)
+            .with_min_inner_size(size)
+            .build(&event_loop)
+            .unwrap()
+    };
+
+    let mut pixels = {
+        let window_size = window.inner_size();
+        let surface_texture = SurfaceTexture::new(window_size.width, window_size.height, &window);
+        Pixels::new(WIDTH as u32, HEIGHT as u32, surface_texture)?
+    };
+
+    let tileset = make_tileset();
+    let map = make_map();
+    let (map_width, map_height) = map.pixel_size();
+    let (mut scroll_x, mut scroll_y) = (0, 0);
+
+    event_loop.run(move |event, _, control_flow| {
+        // Draw the current frame
+        if let Event::RedrawRequested(_) = event {
+            map.draw(
+                &tileset,
+                pixels.get_frame(),
+                WIDTH,
+                scroll_x,
+                scroll_y,
+                &BlitOptions::default(),
+            );
+
+            if pixels
+                .render()
+                .map_err(|e| error!("pixels.render() failed: {}", e))
+                .is_err()
+            {
+                *control_flow = ControlFlow::Exit;
+                return;
+            }
+        }
+
+        // Handle input events
+        if input.update(&event) {
+            if input.quit() {
+                *control_flow = ControlFlow::Exit;
+                return;
+            }
+
+            // Scroll around the map with the arrow keys.
+            if input.key_held(VirtualKeyCode::Left) {
+                scroll_x -= SCROLL_SPEED;
+            }
+            if input.key_held(VirtualKeyCode::Right) {
+                scroll_x += SCROLL_SPEED;
+            }
+            if input.key_held(VirtualKeyCode::Up) {
+                scroll_y -= SCROLL_SPEED;
+            }
+            if input.key_held(VirtualKeyCode::Down) {
+                scroll_y += SCROLL_SPEED;
+            }
+            scroll_x = scroll_x.clamp(0, map_width as i32 - WIDTH);
+            scroll_y = scroll_y.clamp(0, map_height as i32 - HEIGHT);
+
+            window.request_redraw();
+        }
+    });
+}
+
+/// Draws grass, dirt, water and flower tiles side by side. The flower is mostly transparent, for the top layer.
+fn make_tileset() -> SpriteSheet {
+    let size = TILE as i32;
+    let width = size * 4;
+    let mut image = Image::new(width as u32, TILE);
+    let frame = image.pixels_mut();
+
+    let colors = [[60, 150, 60, 255], [140, 100, 60, 255], [40, 90, 200, 255]];
+    for (i, color) in colors.iter().enumerate() {
+        let left = i as i32 * size;
+        pixels_primitives::rect_filled(frame, width, left, 0, left + size - 1, size - 1, color);
+        let edge = [color[0] * 4 / 5, color[1] * 4 / 5, color[2] * 4 / 5, 255];
+        pixels_primitives::rect(frame, width, left, 0, left + size - 1, size - 1, &edge);
+    }
+
+    let (center_x, center_y) = ((FLOWER as i32 * size + size / 2) as f64, (size / 2) as f64);
+    pixels_primitives::circle_filled(frame, width, center_x, center_y, 8.0, &[230, 60, 120, 255]);
+    pixels_primitives::circle_filled(frame, width, center_x, center_y, 3.0, &[250, 220, 40, 255]);
+
+    SpriteSheet::from_grid(image, TILE, TILE)
+}
+
+/// Builds a map with a ground layer of grass, a path and a lake, and a layer of flowers on top of it.
+fn make_map() -> Tilemap {
+    let mut map = Tilemap::new(COLUMNS, ROWS, TILE, TILE);
+    let ground = map.add_layer(vec![Some(GRASS); (COLUMNS * ROWS) as usize]);
+    let flowers = map.add_layer(vec![None; (COLUMNS * ROWS) as usize]);
+
+    for column in 0..COLUMNS {
+        map.set_tile(ground, column, 12, Some(DIRT));
+    }
+    for row in 0..ROWS {
+        map.set_tile(ground, 40, row, Some(DIRT));
+    }
+    for row in 20..35 {
+        for column in 10..30 {
+            let (dx, dy) = (column as i32 - 20, row as i32 - 27);
+            if dx * dx + 2 * dy * dy < 100 {
+                map.set_tile(ground, column, row, Some(WATER));
+            }
+        }
+    }
+    for i in 0..120 {
+        let (column, row) = ((i * 37) % COLUMNS, (i * 23) % ROWS);
+        if map.tile(ground, column, row) == Some(GRASS) {
+            map.set_tile(flowers, column, row, Some(FLOWER));
+        }
+    }
+
+    map
+}
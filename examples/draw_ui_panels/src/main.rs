@@ -0,0 +1,140 @@
+use log::error;
+use pixels::{Pixels, SurfaceTexture};
+use pixels_primitives::{BlitOptions, Image, NineSlice, Region, SliceMode};
+use std::error::Error;
+use winit::dpi::LogicalSize;
+use winit::event::Event;
+use winit::event_loop::{ControlFlow, EventLoop};
+use winit::window::WindowBuilder;
+use winit_input_helper::WinitInputHelper;
+
+const WIDTH: i32 = 800;
+const HEIGHT: i32 = 800;
+
+fn main() -> Result<(), Box<dyn Error>> {
+    let event_loop = EventLoop::new();
+    let mut input = WinitInputHelper::new();
+    let window = {
+        let size = LogicalSize::new(WIDTH as f64, HEIGHT as f64);
+        WindowBuilder::new()
+            .with_title("UI Panels Example")
+            .with_inner_size(size)
+            .with_min_inner_size(size)
+            .build(&event_loop)
+            .unwrap()
+    };
+
+    let mut pixels = {
+        let window_size = window.inner_size();
+        let surface_texture = SurfaceTexture::new(window_size.width, window_size.height, &window);
+        Pixels::new(WIDTH as u32, HEIGHT as u32, surface_texture)?
+    };
+
+    let panel = make_panel();
+    let stretched = NineSlice::uniform(8);
+    let tiled = NineSlice {
+        edges: SliceMode::Tile,
+        center: SliceMode::Tile,
+        ..stretched
+    };
+
+    pixels_primitives::rect_filled(
+        pixels.get_frame(),
+        WIDTH,
+        0,
+        0,
+        WIDTH - 1,
+        HEIGHT - 1,
+        &[50, 50, 60, 255],
+    );
+
+    // The same 32x32 panel drawn at very different sizes, with its corners kept sharp.
+    pixels_primitives::nine_slice(
+        pixels.get_frame(),
+        WIDTH,
+        &panel,
+        &stretched,
+        Region::new(50, 50, 700, 120),
+        &BlitOptions::default(),
+    );
+    pixels_primitives::nine_slice(
+        pixels.get_frame(),
+        WIDTH,
+        &panel,
+        &stretched,
+        Region::new(50, 220, 200, 500),
+        &BlitOptions::default(),
+    );
+
+    // Tiling repeats the stripes of the edges and the dots of the center instead of smearing them.
+    pixels_primitives::nine_slice(
+        pixels.get_frame(),
+        WIDTH,
+        &panel,
+        &tiled,
+        Region::new(300, 220, 450, 300),
+        &BlitOptions::default(),
+    );
+
+    // Too small for the border, so the corners are shrunk to fit.
+    pixels_primitives::nine_slice(
+        pixels.get_frame(),
+        WIDTH,
+        &panel,
+        &stretched,
+        Region::new(300, 600, 12, 12),
+        &BlitOptions::default(),
+    );
+
+    event_loop.run(move |event, _, control_flow| {
+        // Draw the current frame
+        if let Event::RedrawRequested(_) = event {
+            if pixels
+                .render()
+                .map_err(|e| error!("pixels.render() failed: {}", e))
+                .is_err()
+            {
+                *control_flow = ControlFlow::Exit;
+                return;
+            }
+        }
+
+        // Handle input events
+        if input.update(&event) {
+            if input.quit() {
+                *control_flow = ControlFlow::Exit;
+                return;
+            }
+
+            window.request_redraw();
+        }
+    });
+}
+
+/// Draws a 32x32 panel with an 8 pixel striped border, rounded corners and a dotted center.
+fn make_panel() -> Image {
+    let mut panel = Image::new(32, 32);
+    let frame = panel.pixels_mut();
+
+    pixels_primitives::rect_filled(frame, 32, 0, 0, 31, 31, &[200, 170, 90, 255]);
+    let stripe = [150, 120, 50, 255];
+    for i in (0..32).step_by(4) {
+        pixels_primitives::rect_filled(frame, 32, i, 0, i, 7, &stripe);
+        pixels_primitives::rect_filled(frame, 32, i, 24, i, 31, &stripe);
+        pixels_primitives::rect_filled(frame, 32, 0, i, 7, i, &stripe);
+        pixels_primitives::rect_filled(frame, 32, 24, i, 31, i, &stripe);
+    }
+    pixels_primitives::rect_filled(frame, 32, 8, 8, 23, 23, &[30, 40, 90, 255]);
+    for y in (10..24).step_by(4) {
+        for x in (10..24).step_by(4) {
+            pixels_primitives::rect_filled(frame, 32, x, y, x + 1, y + 1, &[70, 90, 160, 255]);
+        }
+    }
+
+    // Cut the corners off, so the panel looks rounded.
+    for (x, y) in [(0, 0), (29, 0), (0, 29), (29, 29)] {
+        pixels_primitives::rect_filled(frame, 32, x, y, x + 2, y + 2, &[0, 0, 0, 0]);
+    }
+
+    panel
+}
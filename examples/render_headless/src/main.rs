@@ -0,0 +1,47 @@
+use pixels_primitives::{ColorStop, FillRule, Image, LinearGradient, SpreadMode};
+use std::env;
+use std::error::Error;
+use std::path::PathBuf;
+
+const WIDTH: i32 = 400;
+const HEIGHT: i32 = 300;
+
+/// Draws a scene into a plain buffer instead of a window, and saves it in every format that can be written.
+fn main() -> Result<(), Box<dyn Error>> {
+    let directory = env::args().nth(1).map(PathBuf::from).unwrap_or_default();
+    let mut frame = vec![0; (WIDTH * HEIGHT * 4) as usize];
+
+    let sky = LinearGradient::new(
+        (0.0, 0.0),
+        (0.0, HEIGHT as f64),
+        vec![
+            ColorStop::new(0.0, [30, 60, 150, 255]),
+            ColorStop::new(1.0, [250, 170, 90, 255]),
+        ],
+        SpreadMode::Pad,
+    );
+    pixels_primitives::rect_filled(&mut frame, WIDTH, 0, 0, WIDTH - 1, HEIGHT - 1, &sky);
+    pixels_primitives::circle_filled(&mut frame, WIDTH, 300.0, 200.0, 50.0, &[255, 230, 120, 255]);
+    pixels_primitives::polygon_filled(
+        &mut frame,
+        WIDTH,
+        &[
+            (0.0, 300.0),
+            (120.0, 150.0),
+            (220.0, 240.0),
+            (310.0, 170.0),
+            (400.0, 300.0),
+        ],
+        FillRule::NonZero,
+        &[40, 50, 60, 255],
+    );
+
+    let image = Image::from_frame(&frame, WIDTH);
+    for name in ["scene.png", "scene.qoi", "scene.ppm"] {
+        let path = directory.join(name);
+        image.save(&path)?;
+        println!("Saved {}", path.display());
+    }
+
+    Ok(())
+}
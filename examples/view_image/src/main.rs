@@ -0,0 +1,93 @@
+use log::error;
+use pixels::{Pixels, SurfaceTexture};
+use pixels_primitives::{BlitOptions, Filter, Image, Region, Stipple};
+use std::env;
+use std::error::Error;
+use winit::dpi::LogicalSize;
+use winit::event::Event;
+use winit::event_loop::{ControlFlow, EventLoop};
+use winit::window::WindowBuilder;
+use winit_input_helper::WinitInputHelper;
+
+const WIDTH: i32 = 800;
+const HEIGHT: i32 = 800;
+
+fn main() -> Result<(), Box<dyn Error>> {
+    let event_loop = EventLoop::new();
+    let mut input = WinitInputHelper::new();
+    let window = {
+        let size = LogicalSize::new(WIDTH as f64, HEIGHT as f64);
+        WindowBuilder::new()
+            .with_title("Image Viewer Example")
+            .with_inner_size(size)
+            .with_min_inner_size(size)
+            .build(&event_loop)
+            .unwrap()
+    };
+
+    let mut pixels = {
+        let window_size = window.inner_size();
+        let surface_texture = SurfaceTexture::new(window_size.width, window_size.height, &window);
+        Pixels::new(WIDTH as u32, HEIGHT as u32, surface_texture)?
+    };
+
+    // Any PNG, QOI, BMP, PPM, PGM or PAM file can be passed as the first argument.
+    let path = env::args()
+        .nth(1)
+        .ok_or("usage: cargo run -p view_image -- <image>")?;
+    let image = Image::open(&path)?;
+
+    pixels_primitives::rect_filled(
+        pixels.get_frame(),
+        WIDTH,
+        0,
+        0,
+        WIDTH - 1,
+        HEIGHT - 1,
+        &Stipple::checkerboard([200, 200, 200, 255], Some([255, 255, 255, 255])),
+    );
+
+    // Shrink images that don't fit, keeping their aspect ratio, and center them.
+    let scale = (WIDTH as f64 / image.width() as f64)
+        .min(HEIGHT as f64 / image.height() as f64)
+        .min(1.0);
+    let width = (image.width() as f64 * scale).round() as u32;
+    let height = (image.height() as f64 * scale).round() as u32;
+    pixels_primitives::blit_scaled(
+        pixels.get_frame(),
+        WIDTH,
+        &image,
+        Region::new(
+            (WIDTH - width as i32) / 2,
+            (HEIGHT - height as i32) / 2,
+            width,
+            height,
+        ),
+        Filter::Bilinear,
+        &BlitOptions::default(),
+    );
+
+    event_loop.run(move |event, _, control_flow| {
+        // Draw the current frame
+        if let Event::RedrawRequested(_) = event {
+            if pixels
+                .render()
+                .map_err(|e| error!("pixels.render() failed: {}", e))
+                .is_err()
+            {
+                *control_flow = ControlFlow::Exit;
+                return;
+            }
+        }
+
+        // Handle input events
+        if input.update(&event) {
+            if input.quit() {
+                *control_flow = ControlFlow::Exit;
+                return;
+            }
+
+            window.request_redraw();
+        }
+    });
+}
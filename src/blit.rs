@@ -0,0 +1,537 @@
+use crate::{get_starting_pixel_index, scanline, FillRule, Image, Paint, Transform};
+
+/// A rectangle of whole pixels, with (x, y) as its top left corner.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Region {
+    /// The x coordinate of the left edge.
+    pub x: i32,
+    /// The y coordinate of the top edge.
+    pub y: i32,
+    /// How many pixels wide the region is.
+    pub width: u32,
+    /// How many pixels tall the region is.
+    pub height: u32,
+}
+
+impl Region {
+    /// Creates a region with its top left corner at (x, y).
+    pub fn new(x: i32, y: i32, width: u32, height: u32) -> Self {
+        Self {
+            x,
+            y,
+            width,
+            height,
+        }
+    }
+
+    /// The part of this region that is also inside of `other`, or `None` if they do not overlap.
+    pub fn intersect(&self, other: &Region) -> Option<Region> {
+        let left = self.x.max(other.x) as i64;
+        let top = self.y.max(other.y) as i64;
+        let right = (self.x as i64 + self.width as i64).min(other.x as i64 + other.width as i64);
+        let bottom = (self.y as i64 + self.height as i64).min(other.y as i64 + other.height as i64);
+        if left >= right || top >= bottom {
+            return None;
+        }
+        Some(Region::new(
+            left as i32,
+            top as i32,
+            (right - left) as u32,
+            (bottom - top) as u32,
+        ))
+    }
+}
+
+/// How the pixels of an image are combined with the pixels already in the frame.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum BlendMode {
+    /// The image's pixels replace the frame's pixels, including their alpha.
+    Replace,
+    /// The image's pixels are drawn over the frame's pixels according to their alpha.
+    Alpha,
+}
+
+/// A clockwise rotation by a multiple of 90 degrees.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Rotation {
+    /// The image is drawn upright.
+    Rotate0,
+    /// The image is turned a quarter clockwise, so its left edge ends up at the top.
+    Rotate90,
+    /// The image is turned upside down.
+    Rotate180,
+    /// The image is turned a quarter counter-clockwise, so its right edge ends up at the top.
+    Rotate270,
+}
+
+/// Options for drawing an [`Image`] into a frame with [`crate::blit`].
+///
+/// The defaults draw the whole image upright with alpha blending and without a color key.
+/// Flips are applied to the source region before it is rotated, and both only move whole pixels around.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct BlitOptions {
+    /// The part of the image to draw, or `None` for the whole image. Parts outside of the image are ignored.
+    pub source: Option<Region>,
+    /// How the image is combined with the frame.
+    pub blend: BlendMode,
+    /// Pixels of the image with exactly this RGB color are skipped, no matter their alpha.
+    pub color_key: Option<[u8; 3]>,
+    /// Mirrors the image left to right.
+    pub flip_x: bool,
+    /// Mirrors the image top to bottom.
+    pub flip_y: bool,
+    /// Turns the image by a multiple of 90 degrees. The top left corner of what is drawn stays at (x, y).
+    pub rotation: Rotation,
+}
+
+impl Default for BlitOptions {
+    fn default() -> Self {
+        Self {
+            source: None,
+            blend: BlendMode::Alpha,
+            color_key: None,
+            flip_x: false,
+            flip_y: false,
+            rotation: Rotation::Rotate0,
+        }
+    }
+}
+
+impl BlitOptions {
+    /// The part of `image` these options draw, clipped to the image.
+    pub(crate) fn source_region(&self, image: &Image) -> Option<Region> {
+        let bounds = Region::new(0, 0, image.width(), image.height());
+        match self.source {
+            Some(source) => source.intersect(&bounds),
+            None => bounds.intersect(&bounds),
+        }
+    }
+
+    /// Whether the image is drawn exactly as it is stored.
+    pub(crate) fn is_upright(&self) -> bool {
+        !self.flip_x && !self.flip_y && self.rotation == Rotation::Rotate0
+    }
+
+    /// The size of the source region once it has been rotated.
+    pub(crate) fn oriented_size(&self, source: &Region) -> (u32, u32) {
+        match self.rotation {
+            Rotation::Rotate0 | Rotation::Rotate180 => (source.width, source.height),
+            Rotation::Rotate90 | Rotation::Rotate270 => (source.height, source.width),
+        }
+    }
+
+    /// Maps a point of the flipped and rotated image back to the image itself, relative to the source region.
+    pub(crate) fn unorient(&self, source: &Region, x: f64, y: f64) -> (f64, f64) {
+        let (width, height) = (source.width as f64, source.height as f64);
+        let (x, y) = match self.rotation {
+            Rotation::Rotate0 => (x, y),
+            Rotation::Rotate90 => (y, height - x),
+            Rotation::Rotate180 => (width - x, height - y),
+            Rotation::Rotate270 => (width - y, x),
+        };
+        (
+            if self.flip_x { width - x } else { x },
+            if self.flip_y { height - y } else { y },
+        )
+    }
+
+    /// Draws a single pixel of the image onto a pixel of the frame.
+    #[inline]
+    pub(crate) fn apply(&self, destination: &mut [u8], source: &[u8]) {
+        if let Some(key) = self.color_key {
+            if source[..3] == key {
+                return;
+            }
+        }
+        match self.blend {
+            BlendMode::Replace => destination.copy_from_slice(source),
+            BlendMode::Alpha => blend_over(destination, source),
+        }
+    }
+}
+
+/// Draws `source` over `destination` using its alpha, with both pixels in straight (not premultiplied) RGBA.
+#[inline]
+pub(crate) fn blend_over(destination: &mut [u8], source: &[u8]) {
+    let source_alpha = source[3] as u32;
+    if source_alpha == 255 {
+        destination.copy_from_slice(source);
+        return;
+    }
+    if source_alpha == 0 {
+        return;
+    }
+
+    let destination_alpha = destination[3] as u32 * (255 - source_alpha) / 255;
+    let alpha = source_alpha + destination_alpha;
+    for channel in 0..3 {
+        destination[channel] = ((source[channel] as u32 * source_alpha
+            + destination[channel] as u32 * destination_alpha
+            + alpha / 2)
+            / alpha) as u8;
+    }
+    destination[3] = alpha as u8;
+}
+
+/// Copies the source region of the image into the frame with its top left corner at (x, y).
+pub fn blit(
+    frame: &mut [u8],
+    canvas_width: i32,
+    canvas_height: i32,
+    image: &Image,
+    x: i32,
+    y: i32,
+    options: &BlitOptions,
+) {
+    let Some(source) = options.source_region(image) else {
+        return;
+    };
+    let canvas = Region::new(0, 0, canvas_width as u32, canvas_height as u32);
+    let (width, height) = options.oriented_size(&source);
+    let Some(visible) = Region::new(x, y, width, height).intersect(&canvas) else {
+        return;
+    };
+
+    let image_width = image.width() as usize;
+    if !options.is_upright() {
+        for destination_y in visible.y..visible.y + visible.height as i32 {
+            let start = get_starting_pixel_index(visible.x, destination_y, canvas_width);
+            let end = start + visible.width as usize * 4;
+            for (i, destination) in frame[start..end].chunks_exact_mut(4).enumerate() {
+                // Map the center of the pixel, which always lands inside a single pixel of the image.
+                let (source_x, source_y) = options.unorient(
+                    &source,
+                    (visible.x + i as i32 - x) as f64 + 0.5,
+                    (destination_y - y) as f64 + 0.5,
+                );
+                let index = ((source.y as usize + source_y as usize) * image_width
+                    + source.x as usize
+                    + source_x as usize)
+                    * 4;
+                options.apply(destination, &image.pixels()[index..index + 4]);
+            }
+        }
+        return;
+    }
+
+    for row in 0..visible.height as i32 {
+        let destination_y = visible.y + row;
+        let source_y = source.y + (destination_y - y);
+        let source_x = source.x + (visible.x - x);
+
+        let start = get_starting_pixel_index(visible.x, destination_y, canvas_width);
+        let end = start + visible.width as usize * 4;
+        let source_start = (source_y as usize * image_width + source_x as usize) * 4;
+        let source_end = source_start + visible.width as usize * 4;
+
+        for (destination, source) in frame[start..end]
+            .chunks_exact_mut(4)
+            .zip(image.pixels()[source_start..source_end].chunks_exact(4))
+        {
+            options.apply(destination, source);
+        }
+    }
+}
+
+/// How an image is sampled when it is drawn at a different size or angle.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Filter {
+    /// Uses the closest pixel of the image, which keeps pixel art crisp.
+    Nearest,
+    /// Blends the four closest pixels of the image, which looks smoother for photos.
+    Bilinear,
+}
+
+/// Reads a pixel of the image, clamped to the source region. Pixels matching the color key come back transparent.
+#[inline]
+fn texel(image: &Image, source: &Region, x: i32, y: i32, color_key: Option<[u8; 3]>) -> [u8; 4] {
+    let x = x.clamp(source.x, source.x + source.width as i32 - 1);
+    let y = y.clamp(source.y, source.y + source.height as i32 - 1);
+    let index = (y as usize * image.width() as usize + x as usize) * 4;
+    let mut rgba = [0; 4];
+    rgba.copy_from_slice(&image.pixels()[index..index + 4]);
+    if color_key.is_some_and(|key| rgba[..3] == key) {
+        return [0; 4];
+    }
+    rgba
+}
+
+/// Samples the image at a position given in image pixels, where (0.5, 0.5) is the center of the top left pixel.
+///
+/// Only pixels inside of `source` are ever read, so neighbouring sprites on a sheet do not bleed into each other.
+pub(crate) fn sample(
+    image: &Image,
+    source: &Region,
+    x: f64,
+    y: f64,
+    filter: Filter,
+    color_key: Option<[u8; 3]>,
+) -> [u8; 4] {
+    match filter {
+        Filter::Nearest => texel(image, source, x.floor() as i32, y.floor() as i32, color_key),
+        Filter::Bilinear => {
+            let (x, y) = (x - 0.5, y - 0.5);
+            let (left, top) = (x.floor(), y.floor());
+            let (fraction_x, fraction_y) = (x - left, y - top);
+            let (left, top) = (left as i32, top as i32);
+
+            // Blend with premultiplied alpha, so transparent pixels don't darken the edges of the image.
+            let mut sum = [0.0; 4];
+            for (dx, dy, weight) in [
+                (0, 0, (1.0 - fraction_x) * (1.0 - fraction_y)),
+                (1, 0, fraction_x * (1.0 - fraction_y)),
+                (0, 1, (1.0 - fraction_x) * fraction_y),
+                (1, 1, fraction_x * fraction_y),
+            ] {
+                let rgba = texel(image, source, left + dx, top + dy, color_key);
+                let alpha = rgba[3] as f64 * weight;
+                for channel in 0..3 {
+                    sum[channel] += rgba[channel] as f64 * alpha;
+                }
+                sum[3] += alpha;
+            }
+
+            if sum[3] <= 0.0 {
+                return [0; 4];
+            }
+            [
+                (sum[0] / sum[3]).round() as u8,
+                (sum[1] / sum[3]).round() as u8,
+                (sum[2] / sum[3]).round() as u8,
+                sum[3].round() as u8,
+            ]
+        }
+    }
+}
+
+/// Draws the source region of the image stretched to fill the destination region of the frame.
+pub fn blit_scaled(
+    frame: &mut [u8],
+    canvas_width: i32,
+    canvas_height: i32,
+    image: &Image,
+    destination: Region,
+    filter: Filter,
+    options: &BlitOptions,
+) {
+    let Some(source) = options.source_region(image) else {
+        return;
+    };
+    let canvas = Region::new(0, 0, canvas_width as u32, canvas_height as u32);
+    let Some(visible) = destination.intersect(&canvas) else {
+        return;
+    };
+
+    // The color key has already been turned into transparency while sampling.
+    let blending = BlitOptions {
+        color_key: None,
+        ..*options
+    };
+    let (width, height) = options.oriented_size(&source);
+    let scale_x = width as f64 / destination.width as f64;
+    let scale_y = height as f64 / destination.height as f64;
+
+    for y in visible.y..visible.y + visible.height as i32 {
+        let oriented_y = (y - destination.y) as f64 * scale_y + scale_y / 2.0;
+        let start = get_starting_pixel_index(visible.x, y, canvas_width);
+        let end = start + visible.width as usize * 4;
+
+        for (i, pixel) in frame[start..end].chunks_exact_mut(4).enumerate() {
+            let x = visible.x + i as i32;
+            let oriented_x = (x - destination.x) as f64 * scale_x + scale_x / 2.0;
+            let (source_x, source_y) = options.unorient(&source, oriented_x, oriented_y);
+            let rgba = sample(
+                image,
+                &source,
+                source.x as f64 + source_x,
+                source.y as f64 + source_y,
+                filter,
+                options.color_key,
+            );
+            blending.apply(pixel, &rgba);
+        }
+    }
+}
+
+/// A paint that samples an image through the inverse of a transform and blends it into the frame.
+struct TransformedImage<'a> {
+    image: &'a Image,
+    source: Region,
+    inverse: Transform,
+    filter: Filter,
+    color_key: Option<[u8; 3]>,
+    blending: BlitOptions,
+}
+
+impl Paint for TransformedImage<'_> {
+    fn color_at(&self, x: i32, y: i32) -> Option<[u8; 4]> {
+        // Map the center of the frame's pixel back into the source region of the image.
+        let (image_x, image_y) = self.inverse.apply(x as f64 + 0.5, y as f64 + 0.5);
+        let (image_x, image_y) = self.blending.unorient(&self.source, image_x, image_y);
+        Some(sample(
+            self.image,
+            &self.source,
+            self.source.x as f64 + image_x,
+            self.source.y as f64 + image_y,
+            self.filter,
+            self.color_key,
+        ))
+    }
+
+    fn fill_span(&self, span: &mut [u8], x: i32, y: i32) {
+        for (i, pixel) in span.chunks_exact_mut(4).enumerate() {
+            if let Some(rgba) = self.color_at(x + i as i32, y) {
+                self.blending.apply(pixel, &rgba);
+            }
+        }
+    }
+}
+
+/// Draws the source region of the image with the transform applied to it, where (0, 0) is the top left corner
+/// of the source region.
+pub fn blit_transformed(
+    frame: &mut [u8],
+    canvas_width: i32,
+    canvas_height: i32,
+    image: &Image,
+    transform: &Transform,
+    filter: Filter,
+    options: &BlitOptions,
+) {
+    let Some(source) = options.source_region(image) else {
+        return;
+    };
+    let Some(inverse) = transform.invert() else {
+        return;
+    };
+
+    // Only the pixels covered by the transformed outline of the image are visited, using the same scanline
+    // rasterizer as `triangle_filled` and the filled polygons, so edges and clipping work the same way for all of them.
+    let (width, height) = options.oriented_size(&source);
+    let (width, height) = (width as f64, height as f64);
+    let corners = [(0.0, 0.0), (width, 0.0), (width, height), (0.0, height)].map(|(x, y)| {
+        // The rasterizer tests the integer corner of each pixel, while the colors are sampled at pixel centers.
+        // Moving the outline by half a pixel makes both use the centers.
+        // Snap away the rounding error of the trigonometry, so a quarter turn doesn't lose a row of pixels.
+        let (x, y) = transform.apply(x, y);
+        (snap(x - 0.5), snap(y - 0.5))
+    });
+    let paint = TransformedImage {
+        image,
+        source,
+        inverse,
+        filter,
+        color_key: options.color_key,
+        // The paint applies the color key itself, so blending its samples must not apply it again.
+        blending: BlitOptions {
+            color_key: None,
+            ..*options
+        },
+    };
+
+    scanline::fill_contours(
+        frame,
+        canvas_width,
+        canvas_height,
+        &[corners],
+        FillRule::NonZero,
+        &paint,
+    );
+}
+
+/// Rounds a coordinate to the nearest whole number if it is within rounding error of it.
+fn snap(value: f64) -> f64 {
+    let rounded = value.round();
+    if (value - rounded).abs() < 1e-9 {
+        rounded
+    } else {
+        value
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// A single row image whose red channel holds the given values.
+    fn row_image(values: &[u8]) -> Image {
+        let pixels = values
+            .iter()
+            .flat_map(|&value| [value, 0, 0, 255])
+            .collect();
+        Image::from_rgba(values.len() as u32, 1, pixels)
+    }
+
+    /// Draws the image into a single row frame and returns the red channel, with 0 where nothing was drawn.
+    fn draw_row(image: &Image, transform: &Transform, width: i32) -> Vec<u8> {
+        let mut frame = vec![0; width as usize * 4];
+        blit_transformed(
+            &mut frame,
+            width,
+            1,
+            image,
+            transform,
+            Filter::Nearest,
+            &BlitOptions::default(),
+        );
+        frame.chunks_exact(4).map(|pixel| pixel[0]).collect()
+    }
+
+    #[test]
+    fn transformed_blit_matches_blit_without_transform() {
+        let image = row_image(&[1, 2, 3]);
+        assert_eq!(draw_row(&image, &Transform::identity(), 5), [1, 2, 3, 0, 0]);
+        assert_eq!(
+            draw_row(&image, &Transform::translate(2.0, 0.0), 5),
+            [0, 0, 1, 2, 3]
+        );
+    }
+
+    #[test]
+    fn fractional_translations_keep_every_column() {
+        let image = row_image(&[1, 2, 3]);
+        assert_eq!(
+            draw_row(&image, &Transform::translate(0.25, 0.0), 5),
+            [1, 2, 3, 0, 0]
+        );
+        // A pixel center exactly on the left edge of the image is inside of it.
+        assert_eq!(
+            draw_row(&image, &Transform::translate(0.5, 0.0), 5),
+            [1, 2, 3, 0, 0]
+        );
+        assert_eq!(
+            draw_row(&image, &Transform::translate(0.75, 0.0), 5),
+            [0, 1, 2, 3, 0]
+        );
+        assert_eq!(
+            draw_row(&image, &Transform::translate(-0.25, 0.0), 5),
+            [1, 2, 3, 0, 0]
+        );
+    }
+
+    #[test]
+    fn scaled_and_translated_columns_are_drawn_evenly() {
+        let image = row_image(&[1, 2, 3]);
+        let transform = Transform::scale(2.0, 1.0).then(&Transform::translate(0.5, 0.0));
+        assert_eq!(draw_row(&image, &transform, 8), [1, 1, 2, 2, 3, 3, 0, 0]);
+    }
+
+    #[test]
+    fn quarter_turns_keep_every_pixel() {
+        let image = Image::from_rgba(2, 1, vec![1, 0, 0, 255, 2, 0, 0, 255]);
+        let transform =
+            Transform::rotate(std::f64::consts::FRAC_PI_2).then(&Transform::translate(1.0, 0.0));
+        let mut frame = vec![0; 2 * 2 * 4];
+        blit_transformed(
+            &mut frame,
+            2,
+            2,
+            &image,
+            &transform,
+            Filter::Nearest,
+            &BlitOptions::default(),
+        );
+        let red: Vec<u8> = frame.chunks_exact(4).map(|pixel| pixel[0]).collect();
+        assert_eq!(red, [1, 0, 2, 0]);
+    }
+}
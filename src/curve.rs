@@ -0,0 +1,127 @@
+use crate::math;
+
+/// Curves are never split more times than this, so a tiny tolerance can't recurse forever.
+const MAXIMUM_DEPTH: u32 = 16;
+
+/// Tolerances smaller than this are raised to it, since 0 or a negative value could never be met.
+const MINIMUM_TOLERANCE: f64 = 0.01;
+
+/// The tolerance used in place of NaN, the same one a new [`Path`](crate::Path) starts with.
+const DEFAULT_TOLERANCE: f64 = 0.25;
+
+/// Raises `tolerance` to at least [`MINIMUM_TOLERANCE`], and replaces NaN with [`DEFAULT_TOLERANCE`].
+pub fn clamp_tolerance(tolerance: f64) -> f64 {
+    if tolerance.is_nan() {
+        DEFAULT_TOLERANCE
+    } else {
+        tolerance.max(MINIMUM_TOLERANCE)
+    }
+}
+
+/// Approximates a quadratic Bézier curve with straight segments, pushing every point after the start onto `points`.
+///
+/// Segments are split until the control point is no further than `tolerance` pixels away from them.
+/// The tolerance is clamped with [`clamp_tolerance`] first.
+pub fn flatten_quad(
+    start: (f64, f64),
+    control: (f64, f64),
+    end: (f64, f64),
+    tolerance: f64,
+    points: &mut Vec<(f64, f64)>,
+) {
+    // A quadratic curve is exactly a cubic curve with both control points 2/3 of the way to the quadratic control point.
+    let control1 = (
+        start.0 + (control.0 - start.0) * 2.0 / 3.0,
+        start.1 + (control.1 - start.1) * 2.0 / 3.0,
+    );
+    let control2 = (
+        end.0 + (control.0 - end.0) * 2.0 / 3.0,
+        end.1 + (control.1 - end.1) * 2.0 / 3.0,
+    );
+    flatten_cubic(start, control1, control2, end, tolerance, points);
+}
+
+/// Approximates a cubic Bézier curve with straight segments, pushing every point after the start onto `points`.
+///
+/// Segments are split until both control points are no further than `tolerance` pixels away from them.
+/// The tolerance is clamped with [`clamp_tolerance`] first.
+pub fn flatten_cubic(
+    start: (f64, f64),
+    control1: (f64, f64),
+    control2: (f64, f64),
+    end: (f64, f64),
+    tolerance: f64,
+    points: &mut Vec<(f64, f64)>,
+) {
+    let tolerance = clamp_tolerance(tolerance);
+    subdivide_cubic(start, control1, control2, end, tolerance, 0, points);
+}
+
+fn subdivide_cubic(
+    start: (f64, f64),
+    control1: (f64, f64),
+    control2: (f64, f64),
+    end: (f64, f64),
+    tolerance: f64,
+    depth: u32,
+    points: &mut Vec<(f64, f64)>,
+) {
+    let flat_enough = math::distance_to_segment(control1, start, end) <= tolerance
+        && math::distance_to_segment(control2, start, end) <= tolerance;
+    if flat_enough || depth >= MAXIMUM_DEPTH {
+        points.push(end);
+        return;
+    }
+
+    // Split the curve in half with de Casteljau's algorithm.
+    let ab = midpoint(start, control1);
+    let bc = midpoint(control1, control2);
+    let cd = midpoint(control2, end);
+    let abc = midpoint(ab, bc);
+    let bcd = midpoint(bc, cd);
+    let middle = midpoint(abc, bcd);
+
+    subdivide_cubic(start, ab, abc, middle, tolerance, depth + 1, points);
+    subdivide_cubic(middle, bcd, cd, end, tolerance, depth + 1, points);
+}
+
+#[inline]
+fn midpoint(a: (f64, f64), b: (f64, f64)) -> (f64, f64) {
+    ((a.0 + b.0) / 2.0, (a.1 + b.1) / 2.0)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// How many segments a wide S-shaped curve is flattened into.
+    fn segments(tolerance: f64) -> usize {
+        let mut points = Vec::new();
+        flatten_cubic(
+            (0.0, 0.0),
+            (100.0, 0.0),
+            (0.0, 100.0),
+            (100.0, 100.0),
+            tolerance,
+            &mut points,
+        );
+        points.len()
+    }
+
+    #[test]
+    fn tiny_and_invalid_tolerances_are_clamped() {
+        let minimum = segments(MINIMUM_TOLERANCE);
+        assert!(minimum < 1 << MAXIMUM_DEPTH, "{} segments", minimum);
+        assert_eq!(segments(0.0), minimum);
+        assert_eq!(segments(-1.0), minimum);
+        assert_eq!(segments(f64::NEG_INFINITY), minimum);
+        assert_eq!(segments(f64::NAN), segments(DEFAULT_TOLERANCE));
+    }
+
+    #[test]
+    fn smaller_tolerances_use_more_segments() {
+        assert!(segments(0.1) > segments(1.0));
+        assert!(segments(1.0) > segments(10.0));
+        assert_eq!(segments(1000.0), 1);
+    }
+}
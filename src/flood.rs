@@ -0,0 +1,178 @@
+use crate::{get_starting_pixel_index, Connectivity};
+
+/// Replaces the connected region of similar colors around the seed, one horizontal run of pixels at a time.
+pub fn fill(
+    frame: &mut [u8],
+    canvas_width: i32,
+    canvas_height: i32,
+    seed: (i32, i32),
+    connectivity: Connectivity,
+    tolerance: u8,
+    rgba: &[u8],
+) {
+    let (seed_x, seed_y) = seed;
+    if (seed_x < 0) || (seed_y < 0) || (seed_x >= canvas_width) || (seed_y >= canvas_height) {
+        return;
+    }
+
+    let seed_index = get_starting_pixel_index(seed_x, seed_y, canvas_width);
+    let mut target = [0; 4];
+    target.copy_from_slice(&frame[seed_index..seed_index + 4]);
+
+    // Filled pixels are remembered, since the new color could still be within the tolerance of the old one.
+    let mut filled = vec![false; (canvas_width * canvas_height) as usize];
+    let fillable = |frame: &[u8], filled: &[bool], x: i32, y: i32| {
+        let index = get_starting_pixel_index(x, y, canvas_width);
+        !filled[index / 4]
+            && frame[index..index + 4]
+                .iter()
+                .zip(target)
+                .all(|(&channel, target)| channel.abs_diff(target) <= tolerance)
+    };
+
+    let mut stack = vec![seed];
+    while let Some((x, y)) = stack.pop() {
+        if !fillable(frame, &filled, x, y) {
+            continue;
+        }
+
+        let mut left_x = x;
+        while left_x > 0 && fillable(frame, &filled, left_x - 1, y) {
+            left_x -= 1;
+        }
+        let mut right_x = x;
+        while right_x < canvas_width - 1 && fillable(frame, &filled, right_x + 1, y) {
+            right_x += 1;
+        }
+
+        let start = get_starting_pixel_index(left_x, y, canvas_width);
+        let end = get_starting_pixel_index(right_x, y, canvas_width) + 4;
+        for pixel in frame[start..end].chunks_exact_mut(4) {
+            pixel.copy_from_slice(rgba);
+        }
+        filled[start / 4..end / 4].fill(true);
+
+        // Diagonal neighbours are reached by looking one pixel past both ends of the run.
+        let (scan_start, scan_end) = match connectivity {
+            Connectivity::Four => (left_x, right_x),
+            Connectivity::Eight => ((left_x - 1).max(0), (right_x + 1).min(canvas_width - 1)),
+        };
+        for neighbour_y in [y - 1, y + 1] {
+            if (neighbour_y < 0) || (neighbour_y >= canvas_height) {
+                continue;
+            }
+
+            // Only the first pixel of every fillable run is pushed, the rest is found when the run is expanded.
+            let mut in_run = false;
+            for neighbour_x in scan_start..=scan_end {
+                let can_fill = fillable(frame, &filled, neighbour_x, neighbour_y);
+                if can_fill && !in_run {
+                    stack.push((neighbour_x, neighbour_y));
+                }
+                in_run = can_fill;
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const WHITE: [u8; 4] = [255, 255, 255, 255];
+    const BLACK: [u8; 4] = [0, 0, 0, 255];
+    const RED: [u8; 4] = [255, 0, 0, 255];
+
+    /// A frame from rows of text, where `#` is black and everything else is white.
+    fn frame_from(rows: &[&str]) -> Vec<u8> {
+        rows.iter()
+            .flat_map(|row| row.chars())
+            .flat_map(|c| if c == '#' { BLACK } else { WHITE })
+            .collect()
+    }
+
+    /// The frame as rows of text, where `#` is black, `r` is red and `.` is white.
+    fn rows_of(frame: &[u8], width: usize) -> Vec<String> {
+        frame
+            .chunks_exact(width * 4)
+            .map(|row| {
+                row.chunks_exact(4)
+                    .map(|pixel| match [pixel[0], pixel[1], pixel[2], pixel[3]] {
+                        BLACK => '#',
+                        RED => 'r',
+                        WHITE => '.',
+                        _ => '?',
+                    })
+                    .collect()
+            })
+            .collect()
+    }
+
+    #[test]
+    fn four_connectivity_stops_at_diagonal_barriers() {
+        let rows = ["..#.", ".#..", "#...", "...."];
+        let mut frame = frame_from(&rows);
+        fill(&mut frame, 4, 4, (0, 0), Connectivity::Four, 0, &RED);
+        assert_eq!(rows_of(&frame, 4), ["rr#.", "r#..", "#...", "...."]);
+    }
+
+    #[test]
+    fn eight_connectivity_crosses_diagonal_barriers() {
+        let rows = ["..#.", ".#..", "#...", "...."];
+        let mut frame = frame_from(&rows);
+        fill(&mut frame, 4, 4, (0, 0), Connectivity::Eight, 0, &RED);
+        assert_eq!(rows_of(&frame, 4), ["rr#r", "r#rr", "#rrr", "rrrr"]);
+    }
+
+    #[test]
+    fn tolerance_is_inclusive_for_every_channel() {
+        let mut frame: Vec<u8> = [
+            [100, 100, 100, 255],
+            [110, 90, 110, 255],
+            [100, 100, 100, 245],
+            [111, 100, 100, 255],
+            [100, 100, 100, 255],
+        ]
+        .concat();
+        fill(&mut frame, 5, 1, (0, 0), Connectivity::Four, 10, &RED);
+
+        let filled: Vec<bool> = frame.chunks_exact(4).map(|pixel| pixel == RED).collect();
+        // Every channel of the second and third pixels is within 10 of the seed, but the fourth has one that isn't,
+        // which also cuts off the last pixel.
+        assert_eq!(filled, [true, true, true, false, false]);
+    }
+
+    #[test]
+    fn filling_with_the_target_color_finishes() {
+        let mut frame = frame_from(&["....", ".##.", "...."]);
+        let before = frame.clone();
+        fill(&mut frame, 4, 3, (0, 0), Connectivity::Eight, 0, &WHITE);
+        assert_eq!(frame, before);
+
+        // The new color is within the tolerance of the old one, so every filled pixel still looks fillable.
+        let near_white = [250, 250, 250, 255];
+        fill(
+            &mut frame,
+            4,
+            3,
+            (0, 0),
+            Connectivity::Four,
+            10,
+            &near_white,
+        );
+        let changed = frame
+            .chunks_exact(4)
+            .filter(|&pixel| pixel == near_white)
+            .count();
+        assert_eq!(changed, 10);
+    }
+
+    #[test]
+    fn seeds_outside_of_the_frame_do_nothing() {
+        let mut frame = frame_from(&["..", ".."]);
+        for seed in [(-1, 0), (0, -1), (2, 0), (0, 2)] {
+            fill(&mut frame, 2, 2, seed, Connectivity::Eight, 255, &RED);
+        }
+        assert_eq!(rows_of(&frame, 2), ["..", ".."]);
+    }
+}
@@ -0,0 +1,914 @@
+use std::error::Error;
+use std::fmt;
+use std::fs;
+use std::io;
+use std::path::Path;
+
+use crate::Image;
+
+/// The reasons an image can fail to load or save.
+#[derive(Debug)]
+pub enum ImageError {
+    /// The file could not be read or written.
+    Io(io::Error),
+    /// The data, or the extension of the file being saved, is not in any of the image formats enabled by the
+    /// crate's features.
+    UnknownFormat,
+    /// The data is in a known format, but uses a part of it that can't be decoded, such as a compressed BMP.
+    Unsupported(String),
+    /// The data is damaged or cut short, or the image can't be stored in the format it is being saved as.
+    Invalid(String),
+}
+
+impl fmt::Display for ImageError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ImageError::Io(error) => write!(f, "failed to read or write the image: {}", error),
+            ImageError::UnknownFormat => write!(f, "the image is not in a supported format"),
+            ImageError::Unsupported(reason) => write!(f, "unsupported image: {}", reason),
+            ImageError::Invalid(reason) => write!(f, "invalid image: {}", reason),
+        }
+    }
+}
+
+impl Error for ImageError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            ImageError::Io(error) => Some(error),
+            _ => None,
+        }
+    }
+}
+
+impl From<io::Error> for ImageError {
+    fn from(error: io::Error) -> Self {
+        ImageError::Io(error)
+    }
+}
+
+impl Image {
+    /// Reads and decodes an image file, see [`Image::from_bytes`].
+    pub fn open<P: AsRef<Path>>(path: P) -> Result<Image, ImageError> {
+        Image::from_bytes(&fs::read(path)?)
+    }
+
+    /// Decodes an image, working out its format from the first few bytes.
+    ///
+    /// Each format has to be enabled with the cargo feature of the same name: `png`, `qoi`, `bmp` and `ppm`, where
+    /// `ppm` also covers the PGM and PAM formats. Formats that aren't enabled return [`ImageError::UnknownFormat`].
+    pub fn from_bytes(bytes: &[u8]) -> Result<Image, ImageError> {
+        match bytes {
+            #[cfg(feature = "png")]
+            [0x89, b'P', b'N', b'G', ..] => Image::from_png(bytes),
+            #[cfg(feature = "qoi")]
+            [b'q', b'o', b'i', b'f', ..] => Image::from_qoi(bytes),
+            #[cfg(feature = "bmp")]
+            [b'B', b'M', ..] => Image::from_bmp(bytes),
+            #[cfg(feature = "ppm")]
+            [b'P', b'2' | b'3' | b'5' | b'6' | b'7', ..] => Image::from_ppm(bytes),
+            _ => Err(ImageError::UnknownFormat),
+        }
+    }
+
+    /// Decodes a PNG image of any color type and bit depth. Only the first frame of an animated PNG is decoded.
+    #[cfg(feature = "png")]
+    pub fn from_png(bytes: &[u8]) -> Result<Image, ImageError> {
+        // Reading from memory can only fail because the data was cut short, so every error means the data is invalid.
+        let png_error = |error: png::DecodingError| ImageError::Invalid(error.to_string());
+        let mut decoder = png::Decoder::new(bytes);
+        // Expands palettes, transparency chunks and low bit depths, and strips 16 bit channels down to 8 bits.
+        decoder.set_transformations(png::Transformations::normalize_to_color8());
+        let mut reader = decoder.read_info().map_err(png_error)?;
+        let mut buffer = vec![0; reader.output_buffer_size()];
+        let info = reader.next_frame(&mut buffer).map_err(png_error)?;
+        buffer.truncate(info.buffer_size());
+
+        let pixels = match info.color_type {
+            png::ColorType::Rgba => buffer,
+            png::ColorType::Rgb => expand(&buffer, 3, |rgb| [rgb[0], rgb[1], rgb[2], 255]),
+            png::ColorType::GrayscaleAlpha => {
+                expand(&buffer, 2, |gray| [gray[0], gray[0], gray[0], gray[1]])
+            }
+            png::ColorType::Grayscale => {
+                expand(&buffer, 1, |gray| [gray[0], gray[0], gray[0], 255])
+            }
+            png::ColorType::Indexed => {
+                return Err(ImageError::Unsupported(
+                    "the palette of the PNG could not be expanded".to_string(),
+                ))
+            }
+        };
+        Ok(Image::from_rgba(info.width, info.height, pixels))
+    }
+
+    /// Decodes a QOI image, with or without an alpha channel.
+    #[cfg(feature = "qoi")]
+    pub fn from_qoi(bytes: &[u8]) -> Result<Image, ImageError> {
+        let qoi_error = |error: qoi::Error| ImageError::Invalid(error.to_string());
+        let mut decoder = qoi::Decoder::new(bytes)
+            .map_err(qoi_error)?
+            .with_channels(qoi::Channels::Rgba);
+        let (width, height) = (decoder.header().width, decoder.header().height);
+        let pixels = decoder.decode_to_vec().map_err(qoi_error)?;
+        Ok(Image::from_rgba(width, height, pixels))
+    }
+
+    /// Decodes an uncompressed or bitfield BMP image with 1, 4, 8, 16, 24 or 32 bits per pixel.
+    ///
+    /// Run length encoded and embedded JPEG or PNG images are not supported.
+    #[cfg(feature = "bmp")]
+    pub fn from_bmp(bytes: &[u8]) -> Result<Image, ImageError> {
+        bmp::decode(bytes)
+    }
+
+    /// Decodes a binary or plain text PPM (`P3`, `P6`), PGM (`P2`, `P5`) or PAM (`P7`) image.
+    ///
+    /// Samples with a maximum value other than 255 are scaled to 8 bits. PAM images with a depth of 2 or 4 have an
+    /// alpha channel; every other image is opaque.
+    #[cfg(feature = "ppm")]
+    pub fn from_ppm(bytes: &[u8]) -> Result<Image, ImageError> {
+        ppm::decode(bytes)
+    }
+
+    /// Encodes and writes the image to a file, picking the format from the file's extension.
+    ///
+    /// Like loading, each format has to be enabled with its cargo feature: `.png` needs `png`, `.qoi` needs `qoi`,
+    /// and `.ppm` and `.pam` need `ppm`. Other extensions return [`ImageError::UnknownFormat`].
+    pub fn save<P: AsRef<Path>>(&self, path: P) -> Result<(), ImageError> {
+        let path = path.as_ref();
+        let extension = path
+            .extension()
+            .and_then(|extension| extension.to_str())
+            .map(|extension| extension.to_ascii_lowercase());
+
+        let bytes: Option<Vec<u8>> = match extension.as_deref() {
+            #[cfg(feature = "png")]
+            Some("png") => Some(self.to_png()?),
+            #[cfg(feature = "qoi")]
+            Some("qoi") => Some(self.to_qoi()?),
+            #[cfg(feature = "ppm")]
+            Some("ppm") => Some(self.to_ppm()),
+            #[cfg(feature = "ppm")]
+            Some("pam") => Some(self.to_pam()),
+            _ => None,
+        };
+        fs::write(path, bytes.ok_or(ImageError::UnknownFormat)?)?;
+        Ok(())
+    }
+
+    /// Encodes the image as an 8 bit RGBA PNG.
+    #[cfg(feature = "png")]
+    pub fn to_png(&self) -> Result<Vec<u8>, ImageError> {
+        let png_error = |error: png::EncodingError| ImageError::Invalid(error.to_string());
+        let mut bytes = Vec::new();
+        let mut encoder = png::Encoder::new(&mut bytes, self.width(), self.height());
+        encoder.set_color(png::ColorType::Rgba);
+        encoder.set_depth(png::BitDepth::Eight);
+        let mut writer = encoder.write_header().map_err(png_error)?;
+        writer.write_image_data(self.pixels()).map_err(png_error)?;
+        writer.finish().map_err(png_error)?;
+        Ok(bytes)
+    }
+
+    /// Encodes the image as an RGBA QOI image.
+    #[cfg(feature = "qoi")]
+    pub fn to_qoi(&self) -> Result<Vec<u8>, ImageError> {
+        qoi::encode_to_vec(self.pixels(), self.width(), self.height())
+            .map_err(|error| ImageError::Invalid(error.to_string()))
+    }
+
+    /// Encodes the image as a binary PPM (`P6`). PPM images have no alpha channel, so it is left out.
+    #[cfg(feature = "ppm")]
+    pub fn to_ppm(&self) -> Vec<u8> {
+        let mut bytes = format!("P6\n{} {}\n255\n", self.width(), self.height()).into_bytes();
+        for pixel in self.pixels().chunks_exact(4) {
+            bytes.extend_from_slice(&pixel[..3]);
+        }
+        bytes
+    }
+
+    /// Encodes the image as a PAM (`P7`) with an alpha channel.
+    #[cfg(feature = "ppm")]
+    pub fn to_pam(&self) -> Vec<u8> {
+        let mut bytes = format!(
+            "P7\nWIDTH {}\nHEIGHT {}\nDEPTH 4\nMAXVAL 255\nTUPLTYPE RGB_ALPHA\nENDHDR\n",
+            self.width(),
+            self.height()
+        )
+        .into_bytes();
+        bytes.extend_from_slice(self.pixels());
+        bytes
+    }
+}
+
+/// Turns pixels of `channels` bytes each into RGBA pixels.
+#[cfg(any(feature = "png", feature = "ppm"))]
+fn expand(buffer: &[u8], channels: usize, to_rgba: impl Fn(&[u8]) -> [u8; 4]) -> Vec<u8> {
+    buffer.chunks_exact(channels).flat_map(to_rgba).collect()
+}
+
+#[cfg(feature = "bmp")]
+mod bmp {
+    use super::ImageError;
+    use crate::Image;
+
+    fn invalid(reason: &str) -> ImageError {
+        ImageError::Invalid(reason.to_string())
+    }
+
+    fn read_u16(bytes: &[u8], offset: usize) -> Result<u16, ImageError> {
+        bytes
+            .get(offset..offset + 2)
+            .map(|le| u16::from_le_bytes([le[0], le[1]]))
+            .ok_or_else(|| invalid("the BMP is cut short"))
+    }
+
+    fn read_u32(bytes: &[u8], offset: usize) -> Result<u32, ImageError> {
+        bytes
+            .get(offset..offset + 4)
+            .map(|le| u32::from_le_bytes([le[0], le[1], le[2], le[3]]))
+            .ok_or_else(|| invalid("the BMP is cut short"))
+    }
+
+    /// Where a color channel is in a 16 or 32 bit pixel.
+    #[derive(Clone, Copy)]
+    struct Mask {
+        mask: u32,
+        shift: u32,
+        maximum: u32,
+    }
+
+    impl Mask {
+        fn new(mask: u32) -> Self {
+            let shift = if mask == 0 { 0 } else { mask.trailing_zeros() };
+            Self {
+                mask,
+                shift,
+                maximum: mask >> shift,
+            }
+        }
+
+        /// The channel's value scaled to 8 bits, or `default` if the pixel doesn't have this channel.
+        fn extract(&self, pixel: u32, default: u8) -> u8 {
+            if self.maximum == 0 {
+                return default;
+            }
+            let value = (pixel & self.mask) >> self.shift;
+            ((value as u64 * 255 + self.maximum as u64 / 2) / self.maximum as u64) as u8
+        }
+    }
+
+    const FILE_HEADER_SIZE: usize = 14;
+    const CORE_HEADER_SIZE: u32 = 12;
+    const RGB: u32 = 0;
+    const BITFIELDS: u32 = 3;
+    const ALPHA_BITFIELDS: u32 = 6;
+
+    pub(super) fn decode(bytes: &[u8]) -> Result<Image, ImageError> {
+        let pixel_offset = read_u32(bytes, 10)? as usize;
+        let header_size = read_u32(bytes, 14)?;
+
+        let (width, height, bits_per_pixel, compression) = if header_size == CORE_HEADER_SIZE {
+            (
+                read_u16(bytes, 18)? as i32,
+                read_u16(bytes, 20)? as i32,
+                read_u16(bytes, 24)?,
+                RGB,
+            )
+        } else if header_size >= 40 {
+            (
+                read_u32(bytes, 18)? as i32,
+                read_u32(bytes, 22)? as i32,
+                read_u16(bytes, 28)?,
+                read_u32(bytes, 30)?,
+            )
+        } else {
+            return Err(invalid("the BMP header has an unknown size"));
+        };
+
+        if width <= 0 || height == 0 {
+            return Err(invalid("the BMP has no pixels"));
+        }
+        // Positive heights are stored bottom row first.
+        let bottom_up = height > 0;
+        let (width, height) = (width as u32, height.unsigned_abs());
+
+        let masks = match (compression, bits_per_pixel) {
+            (RGB, 16) => Some([
+                Mask::new(0x7C00),
+                Mask::new(0x03E0),
+                Mask::new(0x001F),
+                Mask::new(0),
+            ]),
+            (RGB, 32) => Some([
+                Mask::new(0x00FF_0000),
+                Mask::new(0x0000_FF00),
+                Mask::new(0x0000_00FF),
+                Mask::new(0),
+            ]),
+            (BITFIELDS | ALPHA_BITFIELDS, 16 | 32) => {
+                // The masks directly follow the 40 byte header, where the newer headers include them.
+                let offset = FILE_HEADER_SIZE + 40;
+                let has_alpha = compression == ALPHA_BITFIELDS || header_size >= 56;
+                Some([
+                    Mask::new(read_u32(bytes, offset)?),
+                    Mask::new(read_u32(bytes, offset + 4)?),
+                    Mask::new(read_u32(bytes, offset + 8)?),
+                    Mask::new(if has_alpha {
+                        read_u32(bytes, offset + 12)?
+                    } else {
+                        0
+                    }),
+                ])
+            }
+            (RGB, 1 | 4 | 8 | 24) => None,
+            (RGB | BITFIELDS | ALPHA_BITFIELDS, _) => {
+                return Err(ImageError::Unsupported(format!(
+                    "BMPs with {} bits per pixel",
+                    bits_per_pixel
+                )))
+            }
+            _ => return Err(ImageError::Unsupported("compressed BMPs".to_string())),
+        };
+
+        // Palettes hold blue, green, red and an unused byte, or just the first three for the oldest header.
+        let mut palette = Vec::new();
+        if bits_per_pixel <= 8 {
+            let entry_size = if header_size == CORE_HEADER_SIZE {
+                3
+            } else {
+                4
+            };
+            let used = if header_size == CORE_HEADER_SIZE {
+                0
+            } else {
+                read_u32(bytes, 46)?
+            };
+            let count = if used == 0 || used > 1 << bits_per_pixel {
+                1 << bits_per_pixel
+            } else {
+                used as usize
+            };
+            let start = FILE_HEADER_SIZE + header_size as usize;
+            for i in 0..count {
+                let offset = start + i * entry_size;
+                let entry = bytes
+                    .get(offset..offset + 3)
+                    .ok_or_else(|| invalid("the BMP palette is cut short"))?;
+                palette.push([entry[2], entry[1], entry[0], 255]);
+            }
+        }
+
+        // Every row is padded to a multiple of 4 bytes.
+        let stride = (bits_per_pixel as usize * width as usize).div_ceil(32) * 4;
+        let data = stride
+            .checked_mul(height as usize)
+            .and_then(|size| bytes.get(pixel_offset..pixel_offset.checked_add(size)?))
+            .ok_or_else(|| invalid("the BMP pixel data is cut short"))?;
+
+        let mut image = Image::new(width, height);
+        for (y, row) in image
+            .pixels_mut()
+            .chunks_exact_mut(width as usize * 4)
+            .enumerate()
+        {
+            let stored_row = if bottom_up {
+                height as usize - 1 - y
+            } else {
+                y
+            };
+            let source = &data[stored_row * stride..(stored_row + 1) * stride];
+
+            for (x, pixel) in row.chunks_exact_mut(4).enumerate() {
+                let rgba = match (bits_per_pixel, &masks) {
+                    (1 | 4 | 8, _) => {
+                        let bit = x * bits_per_pixel as usize;
+                        let byte = source[bit / 8];
+                        let index = (byte >> (8 - bits_per_pixel as usize - bit % 8))
+                            & ((1 << bits_per_pixel) - 1) as u8;
+                        *palette
+                            .get(index as usize)
+                            .ok_or_else(|| invalid("a BMP pixel is outside of the palette"))?
+                    }
+                    (24, _) => [source[x * 3 + 2], source[x * 3 + 1], source[x * 3], 255],
+                    (16, Some([red, green, blue, alpha])) => {
+                        let value = u16::from_le_bytes([source[x * 2], source[x * 2 + 1]]) as u32;
+                        [
+                            red.extract(value, 0),
+                            green.extract(value, 0),
+                            blue.extract(value, 0),
+                            alpha.extract(value, 255),
+                        ]
+                    }
+                    (_, Some([red, green, blue, alpha])) => {
+                        let value = u32::from_le_bytes([
+                            source[x * 4],
+                            source[x * 4 + 1],
+                            source[x * 4 + 2],
+                            source[x * 4 + 3],
+                        ]);
+                        [
+                            red.extract(value, 0),
+                            green.extract(value, 0),
+                            blue.extract(value, 0),
+                            alpha.extract(value, 255),
+                        ]
+                    }
+                    _ => unreachable!("every other bit depth has been rejected"),
+                };
+                pixel.copy_from_slice(&rgba);
+            }
+        }
+
+        Ok(image)
+    }
+}
+
+#[cfg(feature = "ppm")]
+mod ppm {
+    use super::{expand, ImageError};
+    use crate::Image;
+
+    fn invalid(reason: &str) -> ImageError {
+        ImageError::Invalid(reason.to_string())
+    }
+
+    struct Parser<'a> {
+        bytes: &'a [u8],
+        position: usize,
+    }
+
+    impl Parser<'_> {
+        /// Skips whitespace and comments, which run from a `#` to the end of the line.
+        fn skip_whitespace(&mut self) {
+            while let Some(&byte) = self.bytes.get(self.position) {
+                if byte == b'#' {
+                    while self
+                        .bytes
+                        .get(self.position)
+                        .is_some_and(|&byte| byte != b'\n')
+                    {
+                        self.position += 1;
+                    }
+                } else if byte.is_ascii_whitespace() {
+                    self.position += 1;
+                } else {
+                    break;
+                }
+            }
+        }
+
+        fn token(&mut self) -> Result<&str, ImageError> {
+            self.skip_whitespace();
+            let start = self.position;
+            while self
+                .bytes
+                .get(self.position)
+                .is_some_and(|byte| !byte.is_ascii_whitespace())
+            {
+                self.position += 1;
+            }
+            if start == self.position {
+                return Err(invalid("the image header is cut short"));
+            }
+            std::str::from_utf8(&self.bytes[start..self.position])
+                .map_err(|_| invalid("the image header is not text"))
+        }
+
+        fn number(&mut self) -> Result<u32, ImageError> {
+            self.token()?
+                .parse()
+                .map_err(|_| invalid("expected a number in the image"))
+        }
+    }
+
+    pub(super) fn decode(bytes: &[u8]) -> Result<Image, ImageError> {
+        let mut parser = Parser { bytes, position: 0 };
+        let magic = parser.token()?.to_string();
+
+        let (width, height, depth, maximum) = match magic.as_str() {
+            "P2" | "P5" => (parser.number()?, parser.number()?, 1, parser.number()?),
+            "P3" | "P6" => (parser.number()?, parser.number()?, 3, parser.number()?),
+            "P7" => {
+                let (mut width, mut height, mut depth, mut maximum) = (None, None, None, None);
+                loop {
+                    match parser.token()? {
+                        "ENDHDR" => break,
+                        "WIDTH" => width = Some(parser.number()?),
+                        "HEIGHT" => height = Some(parser.number()?),
+                        "DEPTH" => depth = Some(parser.number()?),
+                        "MAXVAL" => maximum = Some(parser.number()?),
+                        // The tuple type only names what the depth already tells us.
+                        "TUPLTYPE" => {
+                            parser.token()?;
+                        }
+                        _ => return Err(invalid("unknown PAM header field")),
+                    }
+                }
+                match (width, height, depth, maximum) {
+                    (Some(width), Some(height), Some(depth), Some(maximum)) => {
+                        (width, height, depth, maximum)
+                    }
+                    _ => return Err(invalid("the PAM header is missing a field")),
+                }
+            }
+            _ => return Err(ImageError::UnknownFormat),
+        };
+
+        if !(1..=4).contains(&depth) {
+            return Err(ImageError::Unsupported(format!(
+                "PAM images with a depth of {}",
+                depth
+            )));
+        }
+        if maximum == 0 || maximum > u16::MAX as u32 {
+            return Err(invalid(
+                "the maximum sample value must be between 1 and 65535",
+            ));
+        }
+
+        let count = (width as usize)
+            .checked_mul(height as usize)
+            .and_then(|pixels| pixels.checked_mul(depth as usize))
+            .ok_or_else(|| invalid("the image is too large"))?;
+        // Every sample takes up at least one byte, so a damaged header can't make this allocate more than that.
+        let mut samples = Vec::with_capacity(count.min(bytes.len()));
+        if magic == "P2" || magic == "P3" {
+            for _ in 0..count {
+                samples.push(scale(parser.number()?, maximum)?);
+            }
+        } else {
+            // A single whitespace byte separates the header from the binary samples.
+            let start = parser.position + 1;
+            let sample_size = if maximum > 255 { 2 } else { 1 };
+            let data = bytes
+                .get(start..start.saturating_add(count.saturating_mul(sample_size)))
+                .ok_or_else(|| invalid("the image data is cut short"))?;
+            for sample in data.chunks_exact(sample_size) {
+                let value = match sample {
+                    [high, low] => u16::from_be_bytes([*high, *low]) as u32,
+                    _ => sample[0] as u32,
+                };
+                samples.push(scale(value, maximum)?);
+            }
+        }
+
+        let pixels = match depth {
+            1 => expand(&samples, 1, |gray| [gray[0], gray[0], gray[0], 255]),
+            2 => expand(&samples, 2, |gray| [gray[0], gray[0], gray[0], gray[1]]),
+            3 => expand(&samples, 3, |rgb| [rgb[0], rgb[1], rgb[2], 255]),
+            _ => samples,
+        };
+        Ok(Image::from_rgba(width, height, pixels))
+    }
+
+    /// Scales a sample from 0..=maximum to 0..=255.
+    fn scale(value: u32, maximum: u32) -> Result<u8, ImageError> {
+        if value > maximum {
+            return Err(invalid("a sample is larger than the maximum value"));
+        }
+        Ok(((value * 255 + maximum / 2) / maximum) as u8)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// The pixels of an image as a list of RGBA colors, row by row.
+    #[cfg(any(feature = "bmp", feature = "ppm"))]
+    fn colors(image: &Image) -> Vec<[u8; 4]> {
+        image
+            .pixels()
+            .chunks_exact(4)
+            .map(|pixel| [pixel[0], pixel[1], pixel[2], pixel[3]])
+            .collect()
+    }
+
+    #[cfg(feature = "bmp")]
+    mod bmp {
+        use super::*;
+
+        const RED: [u8; 4] = [255, 0, 0, 255];
+        const GREEN: [u8; 4] = [0, 255, 0, 255];
+        const BLUE: [u8; 4] = [0, 0, 255, 255];
+        const BLACK: [u8; 4] = [0, 0, 0, 255];
+        const WHITE: [u8; 4] = [255, 255, 255, 255];
+
+        /// Builds a BMP with a 40 byte header. The rows are given top to bottom and without padding, and are stored
+        /// bottom up unless the height is negative. Masks and RGBA palette entries follow the header.
+        fn bmp(
+            width: i32,
+            height: i32,
+            bits_per_pixel: u16,
+            compression: u32,
+            masks: &[u32],
+            palette: &[[u8; 4]],
+            rows: &[&[u8]],
+        ) -> Vec<u8> {
+            let stride = (bits_per_pixel as usize * width as usize).div_ceil(32) * 4;
+            let mut data = Vec::new();
+            let stored: Vec<&&[u8]> = if height > 0 {
+                rows.iter().rev().collect()
+            } else {
+                rows.iter().collect()
+            };
+            for row in stored {
+                data.extend_from_slice(row);
+                data.resize(data.len() + stride - row.len(), 0);
+            }
+
+            let pixel_offset = 14 + 40 + masks.len() * 4 + palette.len() * 4;
+            let mut bytes = b"BM".to_vec();
+            bytes.extend_from_slice(&((pixel_offset + data.len()) as u32).to_le_bytes());
+            bytes.extend_from_slice(&[0; 4]);
+            bytes.extend_from_slice(&(pixel_offset as u32).to_le_bytes());
+            bytes.extend_from_slice(&40u32.to_le_bytes());
+            bytes.extend_from_slice(&width.to_le_bytes());
+            bytes.extend_from_slice(&height.to_le_bytes());
+            bytes.extend_from_slice(&1u16.to_le_bytes());
+            bytes.extend_from_slice(&bits_per_pixel.to_le_bytes());
+            bytes.extend_from_slice(&compression.to_le_bytes());
+            bytes.extend_from_slice(&(data.len() as u32).to_le_bytes());
+            bytes.extend_from_slice(&[0; 8]);
+            bytes.extend_from_slice(&(palette.len() as u32).to_le_bytes());
+            bytes.extend_from_slice(&[0; 4]);
+            for mask in masks {
+                bytes.extend_from_slice(&mask.to_le_bytes());
+            }
+            for [red, green, blue, _] in palette {
+                bytes.extend_from_slice(&[*blue, *green, *red, 0]);
+            }
+            bytes.extend_from_slice(&data);
+            bytes
+        }
+
+        #[test]
+        fn decodes_1_bit_palettes() {
+            let bytes = bmp(
+                3,
+                2,
+                1,
+                0,
+                &[],
+                &[BLACK, WHITE],
+                &[&[0b1010_0000], &[0b0100_0000]],
+            );
+            let image = Image::from_bytes(&bytes).unwrap();
+            assert_eq!((image.width(), image.height()), (3, 2));
+            assert_eq!(colors(&image), [WHITE, BLACK, WHITE, BLACK, WHITE, BLACK]);
+        }
+
+        #[test]
+        fn decodes_4_bit_palettes() {
+            let bytes = bmp(3, 1, 4, 0, &[], &[RED, GREEN, BLUE], &[&[0x01, 0x20]]);
+            let image = Image::from_bytes(&bytes).unwrap();
+            assert_eq!(colors(&image), [RED, GREEN, BLUE]);
+        }
+
+        #[test]
+        fn decodes_8_bit_palettes() {
+            let bytes = bmp(2, 2, 8, 0, &[], &[RED, GREEN, BLUE], &[&[2, 1], &[0, 2]]);
+            let image = Image::from_bytes(&bytes).unwrap();
+            assert_eq!(colors(&image), [BLUE, GREEN, RED, BLUE]);
+        }
+
+        #[test]
+        fn decodes_16_bit_pixels() {
+            let row: Vec<u8> = [0x7C00u16, 0x03E0, 0x001F]
+                .iter()
+                .flat_map(|value| value.to_le_bytes())
+                .collect();
+            let image = Image::from_bytes(&bmp(3, 1, 16, 0, &[], &[], &[&row])).unwrap();
+            assert_eq!(colors(&image), [RED, GREEN, BLUE]);
+
+            let row: Vec<u8> = [0xF800u16, 0x07E0, 0x001F]
+                .iter()
+                .flat_map(|value| value.to_le_bytes())
+                .collect();
+            let bytes = bmp(3, 1, 16, 3, &[0xF800, 0x07E0, 0x001F], &[], &[&row]);
+            let image = Image::from_bytes(&bytes).unwrap();
+            assert_eq!(colors(&image), [RED, GREEN, BLUE]);
+        }
+
+        #[test]
+        fn decodes_24_bit_pixels_bottom_up() {
+            let bytes = bmp(
+                2,
+                2,
+                24,
+                0,
+                &[],
+                &[],
+                &[&[0, 0, 255, 0, 255, 0], &[255, 0, 0, 0, 0, 0]],
+            );
+            let image = Image::from_bytes(&bytes).unwrap();
+            assert_eq!(colors(&image), [RED, GREEN, BLUE, BLACK]);
+        }
+
+        #[test]
+        fn decodes_24_bit_pixels_top_down() {
+            let bytes = bmp(1, -2, 24, 0, &[], &[], &[&[0, 0, 255], &[255, 0, 0]]);
+            let image = Image::from_bytes(&bytes).unwrap();
+            assert_eq!((image.width(), image.height()), (1, 2));
+            assert_eq!(colors(&image), [RED, BLUE]);
+        }
+
+        #[test]
+        fn decodes_32_bit_pixels() {
+            // Without bitfields the fourth byte is unused, so the pixels are opaque.
+            let bytes = bmp(1, 1, 32, 0, &[], &[], &[&[30, 20, 10, 0]]);
+            assert_eq!(
+                colors(&Image::from_bytes(&bytes).unwrap()),
+                [[10, 20, 30, 255]]
+            );
+
+            let masks = [0x00FF_0000, 0x0000_FF00, 0x0000_00FF, 0xFF00_0000];
+            let bytes = bmp(1, 1, 32, 6, &masks, &[], &[&[30, 20, 10, 128]]);
+            assert_eq!(
+                colors(&Image::from_bytes(&bytes).unwrap()),
+                [[10, 20, 30, 128]]
+            );
+        }
+
+        #[test]
+        fn rejects_truncated_pixel_data() {
+            let mut bytes = bmp(2, 2, 24, 0, &[], &[], &[&[0; 6], &[0; 6]]);
+            bytes.truncate(bytes.len() - 1);
+            assert!(matches!(
+                Image::from_bytes(&bytes),
+                Err(ImageError::Invalid(_))
+            ));
+            assert!(matches!(
+                Image::from_bytes(&bytes[..20]),
+                Err(ImageError::Invalid(_))
+            ));
+        }
+
+        #[test]
+        fn rejects_indices_outside_of_the_palette() {
+            let bytes = bmp(1, 1, 8, 0, &[], &[RED], &[&[3]]);
+            assert!(matches!(
+                Image::from_bytes(&bytes),
+                Err(ImageError::Invalid(_))
+            ));
+        }
+
+        #[test]
+        fn rejects_compressed_images() {
+            let bytes = bmp(1, 1, 8, 1, &[], &[RED], &[&[0]]);
+            assert!(matches!(
+                Image::from_bytes(&bytes),
+                Err(ImageError::Unsupported(_))
+            ));
+        }
+    }
+
+    #[cfg(feature = "ppm")]
+    mod ppm {
+        use super::*;
+
+        #[test]
+        fn decodes_plain_graymaps() {
+            let image = Image::from_bytes(b"P2\n# a comment\n2 1\n15\n0 15\n").unwrap();
+            assert_eq!(colors(&image), [[0, 0, 0, 255], [255, 255, 255, 255]]);
+        }
+
+        #[test]
+        fn decodes_plain_pixmaps() {
+            let image = Image::from_bytes(b"P3 2 1 255 10 20 30 40 50 60").unwrap();
+            assert_eq!(colors(&image), [[10, 20, 30, 255], [40, 50, 60, 255]]);
+        }
+
+        #[test]
+        fn decodes_16_bit_graymaps() {
+            let mut bytes = b"P5 3 1 65535\n".to_vec();
+            bytes.extend_from_slice(&[0x00, 0x00, 0x80, 0x00, 0xFF, 0xFF]);
+            let image = Image::from_bytes(&bytes).unwrap();
+            assert_eq!(
+                colors(&image),
+                [[0, 0, 0, 255], [128, 128, 128, 255], [255, 255, 255, 255]]
+            );
+        }
+
+        #[test]
+        fn decodes_binary_pixmaps() {
+            let mut bytes = b"P6\n1 2\n255\n".to_vec();
+            bytes.extend_from_slice(&[1, 2, 3, 4, 5, 6]);
+            let image = Image::from_bytes(&bytes).unwrap();
+            assert_eq!((image.width(), image.height()), (1, 2));
+            assert_eq!(colors(&image), [[1, 2, 3, 255], [4, 5, 6, 255]]);
+        }
+
+        #[test]
+        fn decodes_pam_with_alpha() {
+            let mut bytes =
+                b"P7\nWIDTH 2\nHEIGHT 1\nDEPTH 2\nMAXVAL 255\nTUPLTYPE GRAYSCALE_ALPHA\nENDHDR\n"
+                    .to_vec();
+            bytes.extend_from_slice(&[10, 20, 30, 40]);
+            let image = Image::from_bytes(&bytes).unwrap();
+            assert_eq!(colors(&image), [[10, 10, 10, 20], [30, 30, 30, 40]]);
+        }
+
+        #[test]
+        fn rejects_truncated_data() {
+            assert!(matches!(
+                Image::from_bytes(b"P6 2 1 255\n\x01\x02\x03"),
+                Err(ImageError::Invalid(_))
+            ));
+            assert!(matches!(
+                Image::from_bytes(b"P3 2 1 255 1 2 3"),
+                Err(ImageError::Invalid(_))
+            ));
+            assert!(matches!(
+                Image::from_bytes(b"P6 2"),
+                Err(ImageError::Invalid(_))
+            ));
+        }
+
+        #[test]
+        fn rejects_samples_above_the_maximum() {
+            assert!(matches!(
+                Image::from_bytes(b"P3 1 1 10 11 0 0"),
+                Err(ImageError::Invalid(_))
+            ));
+            assert!(matches!(
+                Image::from_bytes(b"P5 1 1 10\n\x0B"),
+                Err(ImageError::Invalid(_))
+            ));
+            assert!(matches!(
+                Image::from_bytes(b"P5 1 1 0\n\x00"),
+                Err(ImageError::Invalid(_))
+            ));
+        }
+
+        #[test]
+        fn rejects_oversized_headers_without_allocating() {
+            assert!(matches!(
+                Image::from_bytes(b"P6 4000000000 4000000000 255\n\x00"),
+                Err(ImageError::Invalid(_))
+            ));
+            assert!(matches!(
+                Image::from_bytes(b"P2 4000000000 4000000000 255 1 2 3"),
+                Err(ImageError::Invalid(_))
+            ));
+            assert!(matches!(
+                Image::from_bytes(b"P7\nWIDTH 1\nHEIGHT 1\nDEPTH 5\nMAXVAL 255\nENDHDR\n\x00"),
+                Err(ImageError::Unsupported(_))
+            ));
+        }
+    }
+
+    /// A small image with a different color and alpha in every pixel.
+    #[cfg(any(feature = "png", feature = "qoi", feature = "ppm"))]
+    fn test_image() -> Image {
+        let pixels = (0..6u8)
+            .flat_map(|i| [i * 40, 255 - i * 40, i * 10, 255 - i * 30])
+            .collect();
+        Image::from_rgba(3, 2, pixels)
+    }
+
+    #[test]
+    #[cfg(feature = "png")]
+    fn png_round_trip() {
+        let image = test_image();
+        assert_eq!(Image::from_bytes(&image.to_png().unwrap()).unwrap(), image);
+    }
+
+    #[test]
+    #[cfg(feature = "qoi")]
+    fn qoi_round_trip() {
+        let image = test_image();
+        assert_eq!(Image::from_bytes(&image.to_qoi().unwrap()).unwrap(), image);
+    }
+
+    #[test]
+    #[cfg(feature = "ppm")]
+    fn pam_round_trip() {
+        let image = test_image();
+        assert_eq!(Image::from_bytes(&image.to_pam()).unwrap(), image);
+    }
+
+    #[test]
+    #[cfg(feature = "ppm")]
+    fn ppm_round_trip_drops_alpha() {
+        let image = test_image();
+        let decoded = Image::from_bytes(&image.to_ppm()).unwrap();
+        for (decoded, original) in colors(&decoded).iter().zip(colors(&image)) {
+            assert_eq!(decoded[..3], original[..3]);
+            assert_eq!(decoded[3], 255);
+        }
+    }
+
+    #[test]
+    fn unknown_data_is_rejected() {
+        assert!(matches!(
+            Image::from_bytes(b"GIF89a"),
+            Err(ImageError::UnknownFormat)
+        ));
+        assert!(matches!(
+            Image::from_bytes(&[]),
+            Err(ImageError::UnknownFormat)
+        ));
+    }
+}
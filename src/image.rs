@@ -0,0 +1,89 @@
+/// An owned RGBA image, stored row by row with 4 bytes per pixel, the same layout as a frame of pixels.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Image {
+    width: u32,
+    height: u32,
+    pixels: Vec<u8>,
+}
+
+impl Image {
+    /// Creates a fully transparent image.
+    pub fn new(width: u32, height: u32) -> Self {
+        Self {
+            width,
+            height,
+            pixels: vec![0; width as usize * height as usize * 4],
+        }
+    }
+
+    /// Creates an image from RGBA bytes, stored row by row.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `pixels` does not hold exactly `width * height * 4` bytes.
+    pub fn from_rgba(width: u32, height: u32, pixels: Vec<u8>) -> Self {
+        assert_eq!(
+            pixels.len(),
+            width as usize * height as usize * 4,
+            "pixels must hold exactly width * height * 4 bytes"
+        );
+        Self {
+            width,
+            height,
+            pixels,
+        }
+    }
+
+    /// Copies a whole frame of pixels, or any other canvas, into a new image, such as to save it as a screenshot.
+    ///
+    /// Bytes after the last full row of the frame are ignored.
+    pub fn from_frame(frame: &[u8], canvas_width: i32) -> Self {
+        let width = canvas_width.max(0) as u32;
+        let height = match width {
+            0 => 0,
+            _ => (frame.len() / 4 / width as usize) as u32,
+        };
+        Self::from_rgba(
+            width,
+            height,
+            frame[..width as usize * height as usize * 4].to_vec(),
+        )
+    }
+
+    /// The width of the image in pixels.
+    pub fn width(&self) -> u32 {
+        self.width
+    }
+
+    /// The height of the image in pixels.
+    pub fn height(&self) -> u32 {
+        self.height
+    }
+
+    /// The RGBA bytes of the image, stored row by row.
+    pub fn pixels(&self) -> &[u8] {
+        &self.pixels
+    }
+
+    /// The RGBA bytes of the image, stored row by row. This can be passed to the drawing functions as a frame,
+    /// with the image's width as the canvas width.
+    pub fn pixels_mut(&mut self) -> &mut [u8] {
+        &mut self.pixels
+    }
+
+    /// Consumes the image and returns its RGBA bytes.
+    pub fn into_rgba(self) -> Vec<u8> {
+        self.pixels
+    }
+
+    /// The color of the pixel at (x, y), or `None` if it is outside of the image.
+    pub fn get_pixel(&self, x: i32, y: i32) -> Option<[u8; 4]> {
+        if (x < 0) || (y < 0) || (x as u32 >= self.width) || (y as u32 >= self.height) {
+            return None;
+        }
+        let index = (y as usize * self.width as usize + x as usize) * 4;
+        let mut rgba = [0; 4];
+        rgba.copy_from_slice(&self.pixels[index..index + 4]);
+        Some(rgba)
+    }
+}
@@ -2,7 +2,90 @@
 
 use std::mem;
 
+mod blit;
+mod curve;
+mod flood;
+mod formats;
+mod image;
 mod math;
+mod nine_slice;
+mod paint;
+mod path;
+mod scanline;
+mod sprite;
+mod svg;
+mod tilemap;
+mod transform;
+
+pub use blit::{BlendMode, BlitOptions, Filter, Region, Rotation};
+pub use formats::ImageError;
+pub use image::Image;
+pub use nine_slice::{NineSlice, SliceMode};
+pub use paint::{
+    ColorStop, ConicGradient, Hatch, HatchStyle, ImagePattern, LinearGradient, Paint,
+    RadialGradient, RepeatMode, SpreadMode, Stipple,
+};
+pub use path::{LineCap, LineJoin, Path, StrokeStyle};
+pub use sprite::{Animation, AnimationMode, SpriteSheet};
+pub use svg::SvgPathError;
+pub use tilemap::Tilemap;
+pub use transform::Transform;
+
+/// Where the outline of a shape is drawn relative to the shape's edges.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum StrokeAlignment {
+    /// The outline is drawn entirely inside of the shape.
+    Inside,
+    /// The outline is centered on the edges of the shape.
+    Center,
+    /// The outline is drawn entirely outside of the shape.
+    Outside,
+}
+
+/// Decides which parts of a polygon count as inside when its edges cross over each other.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FillRule {
+    /// A point is inside if a ray from it crosses the edges an odd number of times.
+    /// Overlapping areas of a self-intersecting polygon are left empty.
+    EvenOdd,
+    /// A point is inside if the edges wind around it at least once in either direction.
+    /// Overlapping areas of a self-intersecting polygon are filled.
+    NonZero,
+}
+
+/// Which neighbouring pixels count as connected when flood filling.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Connectivity {
+    /// Only the pixels directly above, below, left and right are connected.
+    Four,
+    /// The diagonal pixels are connected as well.
+    Eight,
+}
+
+impl StrokeAlignment {
+    /// Splits an outline width into how far it reaches outside and inside of the shape's edges.
+    fn split(self, outline_width: f64) -> (f64, f64) {
+        match self {
+            StrokeAlignment::Inside => (0.0, outline_width),
+            StrokeAlignment::Center => (outline_width / 2.0, outline_width / 2.0),
+            StrokeAlignment::Outside => (outline_width, 0.0),
+        }
+    }
+
+    /// Like [`StrokeAlignment::split`], but for shapes that are snapped to whole pixels and `extent` pixels across.
+    ///
+    /// Negative and NaN widths are treated as 0, and the outline never reaches further inside than the shape is wide.
+    fn split_pixels(self, outline_width: f64, extent: i32) -> (i32, i32) {
+        // The cast saturates, so even huge widths stay in range and NaN becomes 0.
+        let outline_width = outline_width.round().max(0.0) as i32;
+        let (outside, inside) = match self {
+            StrokeAlignment::Inside => (0, outline_width),
+            StrokeAlignment::Center => (outline_width / 2, outline_width - (outline_width / 2)),
+            StrokeAlignment::Outside => (outline_width, 0),
+        };
+        (outside, inside.min(extent.max(0)))
+    }
+}
 
 /// Draws a 2d line to a frame of pixels.
 ///
@@ -61,6 +144,27 @@ pub fn line(
     ending_x: f64,
     ending_y: f64,
     rgba: &[u8; 4],
+) {
+    paint_line(
+        frame,
+        canvas_width,
+        starting_x,
+        starting_y,
+        ending_x,
+        ending_y,
+        rgba,
+    );
+}
+
+/// Draws a [`line`] whose pixels are colored by a paint.
+fn paint_line<P: Paint + ?Sized>(
+    frame: &mut [u8],
+    canvas_width: i32,
+    starting_x: f64,
+    starting_y: f64,
+    ending_x: f64,
+    ending_y: f64,
+    paint: &P,
 ) {
     let canvas_height = frame.len() as i32 / 4 / canvas_width;
 
@@ -96,9 +200,9 @@ pub fn line(
     let mut y = my0;
     for x in mx0..mx1 {
         if steep {
-            color_position(y, x, canvas_width, canvas_height, frame, rgba);
+            paint_position(y, x, canvas_width, canvas_height, frame, paint);
         } else {
-            color_position(x, y, canvas_width, canvas_height, frame, rgba);
+            paint_position(x, y, canvas_width, canvas_height, frame, paint);
         }
         error2 += error_increment2;
         if error2 > dx {
@@ -159,6 +263,7 @@ pub fn line(
 ///
 /// ```
 #[warn(missing_docs)]
+#[allow(clippy::too_many_arguments)]
 pub fn triangle(
     frame: &mut [u8],
     canvas_width: i32,
@@ -201,10 +306,10 @@ pub fn triangle(
     );
 }
 
-// TODO: this does not line up perfectly with a normal triangle and I don't know why.
-// TODO: this can be optimized by using barycentric coordinates instead of line sweeping.
-
-/// Draws a filled triangle to a frame of pixels.
+/// Draws an outline of a triangle with a configurable outline width to a frame of pixels.
+///
+/// The corners of the outline are mitered, so very sharp corners reach further out than the outline width.
+/// With [`StrokeAlignment::Inside`], the outline only covers pixels that [`triangle_filled`] covers too.
 ///
 /// # Example
 ///
@@ -223,7 +328,7 @@ pub fn triangle(
 ///     let window = {
 ///     let size = LogicalSize::new(WIDTH as f64, HEIGHT as f64);
 ///     WindowBuilder::new()
-///         .with_title("Filled Triangle Example")
+///         .with_title("Triangle Stroke Example")
 ///         .with_inner_size(size)
 ///         .with_min_inner_size(size)
 ///         .build(&event_loop)
@@ -236,7 +341,7 @@ pub fn triangle(
 ///         Pixels::new(WIDTH as u32, HEIGHT as u32, surface_texture)?
 ///     };
 ///
-///     pixels_primitives::triangle_filled(
+///     pixels_primitives::triangle_stroke(
 ///         pixels.get_frame(),
 ///         WIDTH,
 ///         410,
@@ -245,6 +350,8 @@ pub fn triangle(
 ///         180,
 ///         430,
 ///         430,
+///         4.0,
+///         pixels_primitives::StrokeAlignment::Center,
 ///         &[255, 255, 255, 255],
 ///     );
 ///
@@ -255,7 +362,8 @@ pub fn triangle(
 ///
 /// ```
 #[warn(missing_docs)]
-pub fn triangle_filled(
+#[allow(clippy::too_many_arguments)]
+pub fn triangle_stroke(
     frame: &mut [u8],
     canvas_width: i32,
     v0x: i32,
@@ -264,58 +372,122 @@ pub fn triangle_filled(
     v1y: i32,
     v2x: i32,
     v2y: i32,
+    outline_width: f64,
+    alignment: StrokeAlignment,
     rgba: &[u8; 4],
 ) {
-    let (mut mv0x, mut mv0y, mut mv1x, mut mv1y, mut mv2x, mut mv2y) =
-        (v0x, v0y, v1x, v1y, v2x, v2y);
+    let canvas_height = frame.len() as i32 / 4 / canvas_width;
+    let vertices = [
+        (v0x as f64, v0y as f64),
+        (v1x as f64, v1y as f64),
+        (v2x as f64, v2y as f64),
+    ];
+    let (outside, inside) = alignment.split(outline_width);
 
-    // bubble sort the vectors by y-height
-    math::simple_bubble_sort_vector_by_y(
-        &mut mv0x, &mut mv0y, &mut mv1x, &mut mv1y, &mut mv2x, &mut mv2y,
-    );
+    // An outline that does not reach outside uses the triangle's own edges, so it covers exactly the pixels
+    // along the border of what `triangle_filled` covers.
+    let outer = if outside > 0.0 {
+        math::offset_convex_polygon(&vertices, outside)
+    } else {
+        vertices.to_vec()
+    };
+    let mut contours = vec![outer];
 
-    let total_height = (mv2y - mv0y) as f64;
-    // y will start at the lowest vertex y value, and increment by 1 to the middle vertex y value
-    // this makes it so we're only drawing half of the B boundary
-    // each iteration will draw two points, one on the left side and one on the right (for each y value)
+    // If the outline is wider than the triangle is deep, there is no hole left in the middle.
+    if inside < math::triangle_inradius(&vertices) {
+        contours.push(math::offset_convex_polygon(&vertices, -inside));
+    }
 
-    // draws the first "half" of the triangle
-    for y in (mv0y as i32)..=(mv1y as i32) {
-        let segment_height = (mv1y - mv0y) as f64;
-        let alpha = (y - mv0y) as f64 / total_height;
-        let beta = (y - mv0y) as f64 / segment_height;
+    scanline::fill_contours(
+        frame,
+        canvas_width,
+        canvas_height,
+        &contours,
+        FillRule::EvenOdd,
+        rgba,
+    );
+}
 
-        let left_point_x = mv0x as f64 + ((mv2x - mv0x) as f64 * alpha);
-        let right_point_x = mv0x as f64 + ((mv1x - mv0x) as f64 * beta);
+// TODO: this does not line up perfectly with a normal triangle and I don't know why.
 
-        line(
-            frame,
-            canvas_width,
-            right_point_x,
-            y as f64,
-            left_point_x,
-            y as f64,
-            rgba,
-        );
-    }
+/// Draws a filled triangle to a frame of pixels.
+///
+/// # Example
+///
+/// ```no_run
+/// use pixels::{Pixels, SurfaceTexture};
+/// use winit::dpi::LogicalSize;
+/// use winit::event_loop::{EventLoop};
+/// use std::error::Error;
+/// use winit::window::WindowBuilder;
+///
+/// const WIDTH: i32 = 800;
+/// const HEIGHT: i32 = 800;
+///
+/// fn main() -> Result<(), Box<dyn Error>> {
+///     let event_loop = EventLoop::new();
+///     let window = {
+///     let size = LogicalSize::new(WIDTH as f64, HEIGHT as f64);
+///     WindowBuilder::new()
+///         .with_title("Filled Triangle Example")
+///         .with_inner_size(size)
+///         .with_min_inner_size(size)
+///         .build(&event_loop)
+///         .unwrap()
+///     };
+///
+///     let mut pixels = {
+///         let window_size = window.inner_size();
+///         let surface_texture = SurfaceTexture::new(window_size.width, window_size.height, &window);
+///         Pixels::new(WIDTH as u32, HEIGHT as u32, surface_texture)?
+///     };
+///
+///     pixels_primitives::triangle_filled(
+///         pixels.get_frame(),
+///         WIDTH,
+///         410,
+///         500,
+///         700,
+///         180,
+///         430,
+///         430,
+///         &[255, 255, 255, 255],
+///     );
+///
+///     // Run your event loop here!
+///
+///     Ok(())
+/// }
+///
+/// ```
+#[warn(missing_docs)]
+#[allow(clippy::too_many_arguments)]
+pub fn triangle_filled<P: Paint + ?Sized>(
+    frame: &mut [u8],
+    canvas_width: i32,
+    v0x: i32,
+    v0y: i32,
+    v1x: i32,
+    v1y: i32,
+    v2x: i32,
+    v2y: i32,
+    paint: &P,
+) {
+    let canvas_height = frame.len() as i32 / 4 / canvas_width;
+    let vertices = [
+        (v0x as f64, v0y as f64),
+        (v1x as f64, v1y as f64),
+        (v2x as f64, v2y as f64),
+    ];
 
-    // draws the second "half" of the triangle
-    for y in (mv1y as i32)..=(mv2y as i32) {
-        let segment_height = (mv2y - mv1y) as f64;
-        let alpha = (y - mv0y) as f64 / total_height;
-        let beta = (y - mv1y) as f64 / segment_height;
-        let left_point_x = mv0x as f64 + ((mv2x - mv0x) as f64 * alpha);
-        let right_point_x = mv1x as f64 + ((mv2x - mv1x) as f64 * beta);
-        line(
-            frame,
-            canvas_width,
-            right_point_x,
-            y as f64,
-            left_point_x,
-            y as f64,
-            rgba,
-        );
-    }
+    scanline::fill_contours(
+        frame,
+        canvas_width,
+        canvas_height,
+        &[vertices],
+        FillRule::EvenOdd,
+        paint,
+    );
 }
 
 // TODO: this function can be optimized by removing the square root used in the distance function
@@ -395,6 +567,81 @@ pub fn circle(
     }
 }
 
+/// Draws an outline of a circle with a configurable outline alignment to a frame of pixels.
+///
+/// Using [`StrokeAlignment::Inside`] draws the same outline as [`circle`].
+///
+/// # Example
+///
+/// ```no_run
+/// use pixels::{Pixels, SurfaceTexture};
+/// use winit::dpi::LogicalSize;
+/// use winit::event_loop::{EventLoop};
+/// use std::error::Error;
+/// use winit::window::WindowBuilder;
+///
+/// const WIDTH: i32 = 800;
+/// const HEIGHT: i32 = 800;
+///
+/// fn main() -> Result<(), Box<dyn Error>> {
+///     let event_loop = EventLoop::new();
+///     let window = {
+///     let size = LogicalSize::new(WIDTH as f64, HEIGHT as f64);
+///     WindowBuilder::new()
+///         .with_title("Circle Stroke Example")
+///         .with_inner_size(size)
+///         .with_min_inner_size(size)
+///         .build(&event_loop)
+///         .unwrap()
+///     };
+///
+///     let mut pixels = {
+///         let window_size = window.inner_size();
+///         let surface_texture = SurfaceTexture::new(window_size.width, window_size.height, &window);
+///         Pixels::new(WIDTH as u32, HEIGHT as u32, surface_texture)?
+///     };
+///
+///     pixels_primitives::circle_stroke(
+///         pixels.get_frame(),
+///         WIDTH,
+///         200.0,
+///         200.0,
+///         50.0,
+///         4.0,
+///         pixels_primitives::StrokeAlignment::Outside,
+///         &[255, 255, 255, 255],
+///     );
+///
+///     // Run your event loop here!
+///
+///     Ok(())
+/// }
+///
+/// ```
+#[warn(missing_docs)]
+#[allow(clippy::too_many_arguments)]
+pub fn circle_stroke(
+    frame: &mut [u8],
+    canvas_width: i32,
+    center_x: f64,
+    center_y: f64,
+    radius: f64,
+    outline_width: f64,
+    alignment: StrokeAlignment,
+    rgba: &[u8; 4],
+) {
+    let (outside, inside) = alignment.split(outline_width);
+    circle(
+        frame,
+        canvas_width,
+        center_x,
+        center_y,
+        radius + outside,
+        outside + inside,
+        rgba,
+    );
+}
+
 // TODO: this function can be optimized by removing the square root used in the distance function
 
 /// Draws a filled circle to a frame of pixels.
@@ -445,13 +692,13 @@ pub fn circle(
 ///
 /// ```
 #[warn(missing_docs)]
-pub fn circle_filled(
+pub fn circle_filled<P: Paint + ?Sized>(
     frame: &mut [u8],
     canvas_width: i32,
     center_x: f64,
     center_y: f64,
     radius: f64,
-    rgba: &[u8; 4],
+    paint: &P,
 ) {
     let canvas_height = frame.len() as i32 / 4 / canvas_width;
     let rough_minimum_y = (center_y - radius) as i32;
@@ -463,7 +710,7 @@ pub fn circle_filled(
         for x in rough_minimum_x..=rough_maximum_x {
             let distance = math::distance(center_x, center_y, x as f64, y as f64);
             if distance <= radius {
-                color_position(x, y, canvas_width, canvas_height, frame, rgba);
+                paint_position(x, y, canvas_width, canvas_height, frame, paint);
             }
         }
     }
@@ -582,7 +829,9 @@ pub fn square(
     );
 }
 
-/// Draws a filled square to a frame of pixels.
+/// Draws an outline of a square with a configurable outline width to a frame of pixels.
+///
+/// The outline width is rounded to the nearest whole pixel, and negative or NaN widths draw nothing.
 ///
 /// # Example
 ///
@@ -601,7 +850,7 @@ pub fn square(
 ///     let window = {
 ///     let size = LogicalSize::new(WIDTH as f64, HEIGHT as f64);
 ///     WindowBuilder::new()
-///         .with_title("Square Example")
+///         .with_title("Square Stroke Example")
 ///         .with_inner_size(size)
 ///         .with_min_inner_size(size)
 ///         .build(&event_loop)
@@ -614,12 +863,14 @@ pub fn square(
 ///         Pixels::new(WIDTH as u32, HEIGHT as u32, surface_texture)?
 ///     };
 ///
-///     pixels_primitives::square_filled(
+///     pixels_primitives::square_stroke(
 ///         pixels.get_frame(),
 ///         WIDTH,
 ///         200.0,
 ///         200.0,
 ///         100.0,
+///         4.0,
+///         pixels_primitives::StrokeAlignment::Inside,
 ///         &[255, 255, 255, 255],
 ///     );
 ///
@@ -630,42 +881,47 @@ pub fn square(
 ///
 /// ```
 #[warn(missing_docs)]
-pub fn square_filled(
+#[allow(clippy::too_many_arguments)]
+pub fn square_stroke(
     frame: &mut [u8],
     canvas_width: i32,
     center_x: f64,
     center_y: f64,
     side_length: f64,
+    outline_width: f64,
+    alignment: StrokeAlignment,
     rgba: &[u8; 4],
 ) {
     // Note that rough_maximum_y will not actually be rendered higher than rough_minimum_y, as we are working in the 4th quadrant
+    let canvas_height = frame.len() as i32 / 4 / canvas_width;
     let rough_minimum_y = (center_y - (side_length / 2.0)) as i32;
     let rough_minimum_x = (center_x - (side_length / 2.0)) as i32;
     let rough_maximum_y = (center_y + (side_length / 2.0)) as i32;
     let rough_maximum_x = (center_x + (side_length / 2.0)) as i32;
+    let extent = rough_maximum_x.saturating_sub(rough_minimum_x);
+    let (outside, inside) = alignment.split_pixels(outline_width, extent);
 
-    for (i, pixel) in frame.chunks_exact_mut(4).enumerate() {
-        let x = i as i32 % canvas_width;
-        let y = i as i32 / canvas_width;
-
-        // dont calculate distance if its not within the bounding box
-        if (x < rough_minimum_x)
-            || (x > rough_maximum_x)
-            || (y < rough_minimum_y)
-            || (y > rough_maximum_y)
-        {
-            continue;
-        }
-
-        pixel.copy_from_slice(rgba);
-    }
+    fill_ring(
+        (
+            rough_minimum_x.saturating_sub(outside),
+            rough_minimum_y.saturating_sub(outside),
+            rough_maximum_x.saturating_add(outside),
+            rough_maximum_y.saturating_add(outside),
+        ),
+        (
+            rough_minimum_x.saturating_add(inside),
+            rough_minimum_y.saturating_add(inside),
+            rough_maximum_x.saturating_sub(inside),
+            rough_maximum_y.saturating_sub(inside),
+        ),
+        canvas_width,
+        canvas_height,
+        frame,
+        rgba,
+    );
 }
 
-/// Draws an outline of a rectangle to a frame of pixels.
-///
-/// Note: bottom_left_y and top_right_y are only named correctly mathematically. [pixels](https://docs.rs/pixels/latest/pixels/)
-/// renders in the 4th quadrant, so the y values are flipped, with y=0 starting at the top. This means that bottom_left_y is actually
-/// rendered to the top left of the rectangle, and top_right_y is rendered to the bottom right of the triangle.
+/// Draws a filled square to a frame of pixels.
 ///
 /// # Example
 ///
@@ -684,7 +940,7 @@ pub fn square_filled(
 ///     let window = {
 ///     let size = LogicalSize::new(WIDTH as f64, HEIGHT as f64);
 ///     WindowBuilder::new()
-///         .with_title("Rectangle Example")
+///         .with_title("Square Example")
 ///         .with_inner_size(size)
 ///         .with_min_inner_size(size)
 ///         .build(&event_loop)
@@ -697,13 +953,12 @@ pub fn square_filled(
 ///         Pixels::new(WIDTH as u32, HEIGHT as u32, surface_texture)?
 ///     };
 ///
-///     pixels_primitives::rect(
+///     pixels_primitives::square_filled(
 ///         pixels.get_frame(),
 ///         WIDTH,
-///         200,
-///         200,
-///         500,
-///         300,
+///         200.0,
+///         200.0,
+///         100.0,
 ///         &[255, 255, 255, 255],
 ///     );
 ///
@@ -714,69 +969,181 @@ pub fn square_filled(
 ///
 /// ```
 #[warn(missing_docs)]
-pub fn rect(
+pub fn square_filled<P: Paint + ?Sized>(
     frame: &mut [u8],
     canvas_width: i32,
-    bottom_left_x: i32,
-    bottom_left_y: i32,
-    top_right_x: i32,
-    top_right_y: i32,
-    rgba: &[u8; 4],
+    center_x: f64,
+    center_y: f64,
+    side_length: f64,
+    paint: &P,
 ) {
-    line(
-        frame,
-        canvas_width,
-        bottom_left_x as f64,
-        bottom_left_y as f64,
-        bottom_left_x as f64,
-        top_right_y as f64,
-        rgba,
-    );
+    // Note that rough_maximum_y will not actually be rendered higher than rough_minimum_y, as we are working in the 4th quadrant
+    let canvas_height = frame.len() as i32 / 4 / canvas_width;
+    let rough_minimum_y = (center_y - (side_length / 2.0)) as i32;
+    let rough_minimum_x = (center_x - (side_length / 2.0)) as i32;
+    let rough_maximum_y = (center_y + (side_length / 2.0)) as i32;
+    let rough_maximum_x = (center_x + (side_length / 2.0)) as i32;
 
-    line(
-        frame,
+    fill_box(
+        rough_minimum_x,
+        rough_minimum_y,
+        rough_maximum_x,
+        rough_maximum_y,
         canvas_width,
-        bottom_left_x as f64,
-        bottom_left_y as f64,
-        top_right_x as f64,
-        bottom_left_y as f64,
-        rgba,
-    );
-
-    line(
+        canvas_height,
         frame,
-        canvas_width,
-        top_right_x as f64,
-        bottom_left_y as f64,
-        top_right_x as f64,
-        top_right_y as f64,
-        rgba,
+        paint,
     );
+}
 
-    line(
-        frame,
-        canvas_width,
-        top_right_x as f64,
-        bottom_left_y as f64,
-        top_right_x as f64,
-        top_right_y as f64,
-        rgba,
-    );
-
-    line(
+/// Draws an outline of a square rotated around its center to a frame of pixels.
+///
+/// The outline is the one pixel wide border of the pixels the filled version of the same square covers.
+///
+/// The angle is in radians. Because y=0 starts at the top of the frame, positive angles rotate the square clockwise.
+///
+/// # Example
+///
+/// ```no_run
+/// use pixels::{Pixels, SurfaceTexture};
+/// use winit::dpi::LogicalSize;
+/// use winit::event_loop::{EventLoop};
+/// use std::error::Error;
+/// use winit::window::WindowBuilder;
+///
+/// const WIDTH: i32 = 800;
+/// const HEIGHT: i32 = 800;
+///
+/// fn main() -> Result<(), Box<dyn Error>> {
+///     let event_loop = EventLoop::new();
+///     let window = {
+///     let size = LogicalSize::new(WIDTH as f64, HEIGHT as f64);
+///     WindowBuilder::new()
+///         .with_title("Rotated Square Example")
+///         .with_inner_size(size)
+///         .with_min_inner_size(size)
+///         .build(&event_loop)
+///         .unwrap()
+///     };
+///
+///     let mut pixels = {
+///         let window_size = window.inner_size();
+///         let surface_texture = SurfaceTexture::new(window_size.width, window_size.height, &window);
+///         Pixels::new(WIDTH as u32, HEIGHT as u32, surface_texture)?
+///     };
+///
+///     pixels_primitives::square_rotated(
+///         pixels.get_frame(),
+///         WIDTH,
+///         200.0,
+///         200.0,
+///         100.0,
+///         std::f64::consts::FRAC_PI_8,
+///         &[255, 255, 255, 255],
+///     );
+///
+///     // Run your event loop here!
+///
+///     Ok(())
+/// }
+///
+/// ```
+#[warn(missing_docs)]
+pub fn square_rotated(
+    frame: &mut [u8],
+    canvas_width: i32,
+    center_x: f64,
+    center_y: f64,
+    side_length: f64,
+    angle: f64,
+    rgba: &[u8; 4],
+) {
+    rotated_rect_outline(
         frame,
         canvas_width,
-        top_right_x as f64,
-        top_right_y as f64,
-        bottom_left_x as f64,
-        top_right_y as f64,
+        center_x,
+        center_y,
+        side_length,
+        side_length,
+        angle,
         rgba,
     );
 }
 
-// TODO: make it so this function works with two arbitrary opposite corners
+/// Draws a filled square rotated around its center to a frame of pixels.
+///
+/// The angle is in radians. Because y=0 starts at the top of the frame, positive angles rotate the square clockwise.
+///
+/// # Example
+///
+/// ```no_run
+/// use pixels::{Pixels, SurfaceTexture};
+/// use winit::dpi::LogicalSize;
+/// use winit::event_loop::{EventLoop};
+/// use std::error::Error;
+/// use winit::window::WindowBuilder;
+///
+/// const WIDTH: i32 = 800;
+/// const HEIGHT: i32 = 800;
+///
+/// fn main() -> Result<(), Box<dyn Error>> {
+///     let event_loop = EventLoop::new();
+///     let window = {
+///     let size = LogicalSize::new(WIDTH as f64, HEIGHT as f64);
+///     WindowBuilder::new()
+///         .with_title("Filled Rotated Square Example")
+///         .with_inner_size(size)
+///         .with_min_inner_size(size)
+///         .build(&event_loop)
+///         .unwrap()
+///     };
+///
+///     let mut pixels = {
+///         let window_size = window.inner_size();
+///         let surface_texture = SurfaceTexture::new(window_size.width, window_size.height, &window);
+///         Pixels::new(WIDTH as u32, HEIGHT as u32, surface_texture)?
+///     };
+///
+///     pixels_primitives::square_rotated_filled(
+///         pixels.get_frame(),
+///         WIDTH,
+///         200.0,
+///         200.0,
+///         100.0,
+///         std::f64::consts::FRAC_PI_8,
+///         &[255, 255, 255, 255],
+///     );
+///
+///     // Run your event loop here!
+///
+///     Ok(())
+/// }
+///
+/// ```
+#[warn(missing_docs)]
+pub fn square_rotated_filled<P: Paint + ?Sized>(
+    frame: &mut [u8],
+    canvas_width: i32,
+    center_x: f64,
+    center_y: f64,
+    side_length: f64,
+    angle: f64,
+    paint: &P,
+) {
+    let canvas_height = frame.len() as i32 / 4 / canvas_width;
+    let corners = math::rotated_rect_corners(center_x, center_y, side_length, side_length, angle);
 
-/// Draws a filled rectangle to a frame of pixels.
+    scanline::fill_contours(
+        frame,
+        canvas_width,
+        canvas_height,
+        &[corners],
+        FillRule::NonZero,
+        paint,
+    );
+}
+
+/// Draws an outline of a rectangle to a frame of pixels.
 ///
 /// Note: bottom_left_y and top_right_y are only named correctly mathematically. [pixels](https://docs.rs/pixels/latest/pixels/)
 /// renders in the 4th quadrant, so the y values are flipped, with y=0 starting at the top. This means that bottom_left_y is actually
@@ -799,7 +1166,7 @@ pub fn rect(
 ///     let window = {
 ///     let size = LogicalSize::new(WIDTH as f64, HEIGHT as f64);
 ///     WindowBuilder::new()
-///         .with_title("Filled Rectangle Example")
+///         .with_title("Rectangle Example")
 ///         .with_inner_size(size)
 ///         .with_min_inner_size(size)
 ///         .build(&event_loop)
@@ -812,7 +1179,7 @@ pub fn rect(
 ///         Pixels::new(WIDTH as u32, HEIGHT as u32, surface_texture)?
 ///     };
 ///
-///     pixels_primitives::rect_filled(
+///     pixels_primitives::rect(
 ///         pixels.get_frame(),
 ///         WIDTH,
 ///         200,
@@ -829,7 +1196,7 @@ pub fn rect(
 ///
 /// ```
 #[warn(missing_docs)]
-pub fn rect_filled(
+pub fn rect(
     frame: &mut [u8],
     canvas_width: i32,
     bottom_left_x: i32,
@@ -838,45 +1205,2317 @@ pub fn rect_filled(
     top_right_y: i32,
     rgba: &[u8; 4],
 ) {
-    assert!(
-        bottom_left_x <= top_right_x,
-        "bottom_left_x must be smaller or equal to top_right_x"
+    line(
+        frame,
+        canvas_width,
+        bottom_left_x as f64,
+        bottom_left_y as f64,
+        bottom_left_x as f64,
+        top_right_y as f64,
+        rgba,
     );
-    assert!(
-        bottom_left_y <= top_right_y,
-        "bottom_left_y must be smaller or equal to top_right_y"
+
+    line(
+        frame,
+        canvas_width,
+        bottom_left_x as f64,
+        bottom_left_y as f64,
+        top_right_x as f64,
+        bottom_left_y as f64,
+        rgba,
     );
-    for (i, pixel) in frame.chunks_exact_mut(4).enumerate() {
-        let x = i as i32 % canvas_width;
-        let y = i as i32 / canvas_width;
 
-        // dont calculate distance if its not within the bounding box
-        if (x < bottom_left_x) || (x > top_right_x) || (y < bottom_left_y) || (y > top_right_y) {
-            continue;
-        }
+    line(
+        frame,
+        canvas_width,
+        top_right_x as f64,
+        bottom_left_y as f64,
+        top_right_x as f64,
+        top_right_y as f64,
+        rgba,
+    );
 
-        pixel.copy_from_slice(rgba);
-    }
-}
+    line(
+        frame,
+        canvas_width,
+        top_right_x as f64,
+        bottom_left_y as f64,
+        top_right_x as f64,
+        top_right_y as f64,
+        rgba,
+    );
 
-#[inline]
-fn get_starting_pixel_index(x: i32, y: i32, canvas_width: i32) -> usize {
-    (((y * canvas_width) + (x)) * 4) as usize
+    line(
+        frame,
+        canvas_width,
+        top_right_x as f64,
+        top_right_y as f64,
+        bottom_left_x as f64,
+        top_right_y as f64,
+        rgba,
+    );
 }
 
-#[inline]
-fn color_position(
-    x: i32,
-    y: i32,
-    canvas_width: i32,
-    canvas_height: i32,
+/// Draws an outline of a rectangle with a configurable outline width to a frame of pixels.
+///
+/// The outline width is rounded to the nearest whole pixel, and negative or NaN widths draw nothing.
+/// The corners can be given in any order.
+///
+/// # Example
+///
+/// ```no_run
+/// use pixels::{Pixels, SurfaceTexture};
+/// use winit::dpi::LogicalSize;
+/// use winit::event_loop::{EventLoop};
+/// use std::error::Error;
+/// use winit::window::WindowBuilder;
+///
+/// const WIDTH: i32 = 800;
+/// const HEIGHT: i32 = 800;
+///
+/// fn main() -> Result<(), Box<dyn Error>> {
+///     let event_loop = EventLoop::new();
+///     let window = {
+///     let size = LogicalSize::new(WIDTH as f64, HEIGHT as f64);
+///     WindowBuilder::new()
+///         .with_title("Rectangle Stroke Example")
+///         .with_inner_size(size)
+///         .with_min_inner_size(size)
+///         .build(&event_loop)
+///         .unwrap()
+///     };
+///
+///     let mut pixels = {
+///         let window_size = window.inner_size();
+///         let surface_texture = SurfaceTexture::new(window_size.width, window_size.height, &window);
+///         Pixels::new(WIDTH as u32, HEIGHT as u32, surface_texture)?
+///     };
+///
+///     pixels_primitives::rect_stroke(
+///         pixels.get_frame(),
+///         WIDTH,
+///         200,
+///         200,
+///         500,
+///         300,
+///         4.0,
+///         pixels_primitives::StrokeAlignment::Inside,
+///         &[255, 255, 255, 255],
+///     );
+///
+///     // Run your event loop here!
+///
+///     Ok(())
+/// }
+///
+/// ```
+#[warn(missing_docs)]
+#[allow(clippy::too_many_arguments)]
+pub fn rect_stroke(
     frame: &mut [u8],
-    rgba: &[u8],
+    canvas_width: i32,
+    bottom_left_x: i32,
+    bottom_left_y: i32,
+    top_right_x: i32,
+    top_right_y: i32,
+    outline_width: f64,
+    alignment: StrokeAlignment,
+    rgba: &[u8; 4],
 ) {
-    if (x < 0) || (y < 0) || (x >= canvas_width) || (y >= canvas_height) {
-        return;
-    }
-    let index = get_starting_pixel_index(x, y, canvas_width);
-    let pixel = &mut frame[index..index + 4];
-    pixel.copy_from_slice(rgba);
+    let canvas_height = frame.len() as i32 / 4 / canvas_width;
+    let (minimum_x, maximum_x) = (
+        bottom_left_x.min(top_right_x),
+        bottom_left_x.max(top_right_x),
+    );
+    let (minimum_y, maximum_y) = (
+        bottom_left_y.min(top_right_y),
+        bottom_left_y.max(top_right_y),
+    );
+    let extent = maximum_x
+        .saturating_sub(minimum_x)
+        .max(maximum_y.saturating_sub(minimum_y));
+    let (outside, inside) = alignment.split_pixels(outline_width, extent);
+
+    fill_ring(
+        (
+            minimum_x.saturating_sub(outside),
+            minimum_y.saturating_sub(outside),
+            maximum_x.saturating_add(outside),
+            maximum_y.saturating_add(outside),
+        ),
+        (
+            minimum_x.saturating_add(inside),
+            minimum_y.saturating_add(inside),
+            maximum_x.saturating_sub(inside),
+            maximum_y.saturating_sub(inside),
+        ),
+        canvas_width,
+        canvas_height,
+        frame,
+        rgba,
+    );
+}
+
+// TODO: make it so this function works with two arbitrary opposite corners
+
+/// Draws a filled rectangle to a frame of pixels.
+///
+/// Note: bottom_left_y and top_right_y are only named correctly mathematically. [pixels](https://docs.rs/pixels/latest/pixels/)
+/// renders in the 4th quadrant, so the y values are flipped, with y=0 starting at the top. This means that bottom_left_y is actually
+/// rendered to the top left of the rectangle, and top_right_y is rendered to the bottom right of the triangle.
+///
+/// # Example
+///
+/// ```no_run
+/// use pixels::{Pixels, SurfaceTexture};
+/// use winit::dpi::LogicalSize;
+/// use winit::event_loop::{EventLoop};
+/// use std::error::Error;
+/// use winit::window::WindowBuilder;
+///
+/// const WIDTH: i32 = 800;
+/// const HEIGHT: i32 = 800;
+///
+/// fn main() -> Result<(), Box<dyn Error>> {
+///     let event_loop = EventLoop::new();
+///     let window = {
+///     let size = LogicalSize::new(WIDTH as f64, HEIGHT as f64);
+///     WindowBuilder::new()
+///         .with_title("Filled Rectangle Example")
+///         .with_inner_size(size)
+///         .with_min_inner_size(size)
+///         .build(&event_loop)
+///         .unwrap()
+///     };
+///
+///     let mut pixels = {
+///         let window_size = window.inner_size();
+///         let surface_texture = SurfaceTexture::new(window_size.width, window_size.height, &window);
+///         Pixels::new(WIDTH as u32, HEIGHT as u32, surface_texture)?
+///     };
+///
+///     pixels_primitives::rect_filled(
+///         pixels.get_frame(),
+///         WIDTH,
+///         200,
+///         200,
+///         500,
+///         300,
+///         &[255, 255, 255, 255],
+///     );
+///
+///     // Run your event loop here!
+///
+///     Ok(())
+/// }
+///
+/// ```
+#[warn(missing_docs)]
+pub fn rect_filled<P: Paint + ?Sized>(
+    frame: &mut [u8],
+    canvas_width: i32,
+    bottom_left_x: i32,
+    bottom_left_y: i32,
+    top_right_x: i32,
+    top_right_y: i32,
+    paint: &P,
+) {
+    assert!(
+        bottom_left_x <= top_right_x,
+        "bottom_left_x must be smaller or equal to top_right_x"
+    );
+    assert!(
+        bottom_left_y <= top_right_y,
+        "bottom_left_y must be smaller or equal to top_right_y"
+    );
+    let canvas_height = frame.len() as i32 / 4 / canvas_width;
+
+    fill_box(
+        bottom_left_x,
+        bottom_left_y,
+        top_right_x,
+        top_right_y,
+        canvas_width,
+        canvas_height,
+        frame,
+        paint,
+    );
+}
+
+/// Draws an outline of a rectangle rotated around its center to a frame of pixels.
+///
+/// The outline is the one pixel wide border of the pixels the filled version of the same rectangle covers.
+///
+/// The angle is in radians. Because y=0 starts at the top of the frame, positive angles rotate the rectangle clockwise.
+///
+/// # Example
+///
+/// ```no_run
+/// use pixels::{Pixels, SurfaceTexture};
+/// use winit::dpi::LogicalSize;
+/// use winit::event_loop::{EventLoop};
+/// use std::error::Error;
+/// use winit::window::WindowBuilder;
+///
+/// const WIDTH: i32 = 800;
+/// const HEIGHT: i32 = 800;
+///
+/// fn main() -> Result<(), Box<dyn Error>> {
+///     let event_loop = EventLoop::new();
+///     let window = {
+///     let size = LogicalSize::new(WIDTH as f64, HEIGHT as f64);
+///     WindowBuilder::new()
+///         .with_title("Rotated Rectangle Example")
+///         .with_inner_size(size)
+///         .with_min_inner_size(size)
+///         .build(&event_loop)
+///         .unwrap()
+///     };
+///
+///     let mut pixels = {
+///         let window_size = window.inner_size();
+///         let surface_texture = SurfaceTexture::new(window_size.width, window_size.height, &window);
+///         Pixels::new(WIDTH as u32, HEIGHT as u32, surface_texture)?
+///     };
+///
+///     pixels_primitives::rect_rotated(
+///         pixels.get_frame(),
+///         WIDTH,
+///         350.0,
+///         250.0,
+///         300.0,
+///         100.0,
+///         std::f64::consts::FRAC_PI_6,
+///         &[255, 255, 255, 255],
+///     );
+///
+///     // Run your event loop here!
+///
+///     Ok(())
+/// }
+///
+/// ```
+#[warn(missing_docs)]
+#[allow(clippy::too_many_arguments)]
+pub fn rect_rotated(
+    frame: &mut [u8],
+    canvas_width: i32,
+    center_x: f64,
+    center_y: f64,
+    width: f64,
+    height: f64,
+    angle: f64,
+    rgba: &[u8; 4],
+) {
+    rotated_rect_outline(
+        frame,
+        canvas_width,
+        center_x,
+        center_y,
+        width,
+        height,
+        angle,
+        rgba,
+    );
+}
+
+/// Draws a filled rectangle rotated around its center to a frame of pixels.
+///
+/// The angle is in radians. Because y=0 starts at the top of the frame, positive angles rotate the rectangle clockwise.
+///
+/// # Example
+///
+/// ```no_run
+/// use pixels::{Pixels, SurfaceTexture};
+/// use winit::dpi::LogicalSize;
+/// use winit::event_loop::{EventLoop};
+/// use std::error::Error;
+/// use winit::window::WindowBuilder;
+///
+/// const WIDTH: i32 = 800;
+/// const HEIGHT: i32 = 800;
+///
+/// fn main() -> Result<(), Box<dyn Error>> {
+///     let event_loop = EventLoop::new();
+///     let window = {
+///     let size = LogicalSize::new(WIDTH as f64, HEIGHT as f64);
+///     WindowBuilder::new()
+///         .with_title("Filled Rotated Rectangle Example")
+///         .with_inner_size(size)
+///         .with_min_inner_size(size)
+///         .build(&event_loop)
+///         .unwrap()
+///     };
+///
+///     let mut pixels = {
+///         let window_size = window.inner_size();
+///         let surface_texture = SurfaceTexture::new(window_size.width, window_size.height, &window);
+///         Pixels::new(WIDTH as u32, HEIGHT as u32, surface_texture)?
+///     };
+///
+///     pixels_primitives::rect_rotated_filled(
+///         pixels.get_frame(),
+///         WIDTH,
+///         350.0,
+///         250.0,
+///         300.0,
+///         100.0,
+///         std::f64::consts::FRAC_PI_6,
+///         &[255, 255, 255, 255],
+///     );
+///
+///     // Run your event loop here!
+///
+///     Ok(())
+/// }
+///
+/// ```
+#[warn(missing_docs)]
+#[allow(clippy::too_many_arguments)]
+pub fn rect_rotated_filled<P: Paint + ?Sized>(
+    frame: &mut [u8],
+    canvas_width: i32,
+    center_x: f64,
+    center_y: f64,
+    width: f64,
+    height: f64,
+    angle: f64,
+    paint: &P,
+) {
+    let canvas_height = frame.len() as i32 / 4 / canvas_width;
+    let corners = math::rotated_rect_corners(center_x, center_y, width, height, angle);
+
+    scanline::fill_contours(
+        frame,
+        canvas_width,
+        canvas_height,
+        &[corners],
+        FillRule::NonZero,
+        paint,
+    );
+}
+
+/// Draws an outline of a polygon to a frame of pixels.
+///
+/// The last point is connected back to the first point.
+///
+/// # Example
+///
+/// ```no_run
+/// use pixels::{Pixels, SurfaceTexture};
+/// use winit::dpi::LogicalSize;
+/// use winit::event_loop::{EventLoop};
+/// use std::error::Error;
+/// use winit::window::WindowBuilder;
+///
+/// const WIDTH: i32 = 800;
+/// const HEIGHT: i32 = 800;
+///
+/// fn main() -> Result<(), Box<dyn Error>> {
+///     let event_loop = EventLoop::new();
+///     let window = {
+///     let size = LogicalSize::new(WIDTH as f64, HEIGHT as f64);
+///     WindowBuilder::new()
+///         .with_title("Polygon Example")
+///         .with_inner_size(size)
+///         .with_min_inner_size(size)
+///         .build(&event_loop)
+///         .unwrap()
+///     };
+///
+///     let mut pixels = {
+///         let window_size = window.inner_size();
+///         let surface_texture = SurfaceTexture::new(window_size.width, window_size.height, &window);
+///         Pixels::new(WIDTH as u32, HEIGHT as u32, surface_texture)?
+///     };
+///
+///     pixels_primitives::polygon(
+///         pixels.get_frame(),
+///         WIDTH,
+///         &[(200.0, 100.0), (500.0, 150.0), (350.0, 250.0), (600.0, 400.0), (150.0, 350.0)],
+///         &[255, 255, 255, 255],
+///     );
+///
+///     // Run your event loop here!
+///
+///     Ok(())
+/// }
+///
+/// ```
+#[warn(missing_docs)]
+pub fn polygon(frame: &mut [u8], canvas_width: i32, points: &[(f64, f64)], rgba: &[u8; 4]) {
+    for (i, &(x0, y0)) in points.iter().enumerate() {
+        let (x1, y1) = points[(i + 1) % points.len()];
+        line(frame, canvas_width, x0, y0, x1, y1, rgba);
+    }
+}
+
+/// Draws a filled polygon to a frame of pixels.
+///
+/// The polygon can be concave or self-intersecting, in which case `fill_rule` decides which of the
+/// overlapping areas are filled. The last point is connected back to the first point.
+///
+/// # Example
+///
+/// ```no_run
+/// use pixels::{Pixels, SurfaceTexture};
+/// use winit::dpi::LogicalSize;
+/// use winit::event_loop::{EventLoop};
+/// use std::error::Error;
+/// use winit::window::WindowBuilder;
+///
+/// const WIDTH: i32 = 800;
+/// const HEIGHT: i32 = 800;
+///
+/// fn main() -> Result<(), Box<dyn Error>> {
+///     let event_loop = EventLoop::new();
+///     let window = {
+///     let size = LogicalSize::new(WIDTH as f64, HEIGHT as f64);
+///     WindowBuilder::new()
+///         .with_title("Filled Polygon Example")
+///         .with_inner_size(size)
+///         .with_min_inner_size(size)
+///         .build(&event_loop)
+///         .unwrap()
+///     };
+///
+///     let mut pixels = {
+///         let window_size = window.inner_size();
+///         let surface_texture = SurfaceTexture::new(window_size.width, window_size.height, &window);
+///         Pixels::new(WIDTH as u32, HEIGHT as u32, surface_texture)?
+///     };
+///
+///     pixels_primitives::polygon_filled(
+///         pixels.get_frame(),
+///         WIDTH,
+///         &[(200.0, 100.0), (500.0, 150.0), (350.0, 250.0), (600.0, 400.0), (150.0, 350.0)],
+///         pixels_primitives::FillRule::EvenOdd,
+///         &[255, 255, 255, 255],
+///     );
+///
+///     // Run your event loop here!
+///
+///     Ok(())
+/// }
+///
+/// ```
+#[warn(missing_docs)]
+pub fn polygon_filled<P: Paint + ?Sized>(
+    frame: &mut [u8],
+    canvas_width: i32,
+    points: &[(f64, f64)],
+    fill_rule: FillRule,
+    paint: &P,
+) {
+    let canvas_height = frame.len() as i32 / 4 / canvas_width;
+    scanline::fill_contours(
+        frame,
+        canvas_width,
+        canvas_height,
+        &[points],
+        fill_rule,
+        paint,
+    );
+}
+
+/// Draws several polygon contours to a frame of pixels, filled together as one shape.
+///
+/// This is how shapes with holes are drawn: pass the outer boundary along with every hole, and `fill_rule` decides
+/// which areas are inside. With [`FillRule::EvenOdd`] any contour inside of another becomes a hole. With [`FillRule::NonZero`]
+/// a hole has to run in the opposite direction of the contour around it. Every contour is closed back to its first point.
+///
+/// # Example
+///
+/// ```no_run
+/// use pixels::{Pixels, SurfaceTexture};
+/// use winit::dpi::LogicalSize;
+/// use winit::event_loop::{EventLoop};
+/// use std::error::Error;
+/// use winit::window::WindowBuilder;
+///
+/// const WIDTH: i32 = 800;
+/// const HEIGHT: i32 = 800;
+///
+/// fn main() -> Result<(), Box<dyn Error>> {
+///     let event_loop = EventLoop::new();
+///     let window = {
+///     let size = LogicalSize::new(WIDTH as f64, HEIGHT as f64);
+///     WindowBuilder::new()
+///         .with_title("Polygon Contours Example")
+///         .with_inner_size(size)
+///         .with_min_inner_size(size)
+///         .build(&event_loop)
+///         .unwrap()
+///     };
+///
+///     let mut pixels = {
+///         let window_size = window.inner_size();
+///         let surface_texture = SurfaceTexture::new(window_size.width, window_size.height, &window);
+///         Pixels::new(WIDTH as u32, HEIGHT as u32, surface_texture)?
+///     };
+///
+///     // A square with a square hole in the middle.
+///     pixels_primitives::polygon_contours_filled(
+///         pixels.get_frame(),
+///         WIDTH,
+///         &[
+///             vec![(100.0, 100.0), (400.0, 100.0), (400.0, 400.0), (100.0, 400.0)],
+///             vec![(200.0, 200.0), (200.0, 300.0), (300.0, 300.0), (300.0, 200.0)],
+///         ],
+///         pixels_primitives::FillRule::NonZero,
+///         &[255, 255, 255, 255],
+///     );
+///
+///     // Run your event loop here!
+///
+///     Ok(())
+/// }
+///
+/// ```
+#[warn(missing_docs)]
+pub fn polygon_contours_filled<C: AsRef<[(f64, f64)]>, P: Paint + ?Sized>(
+    frame: &mut [u8],
+    canvas_width: i32,
+    contours: &[C],
+    fill_rule: FillRule,
+    paint: &P,
+) {
+    let canvas_height = frame.len() as i32 / 4 / canvas_width;
+    scanline::fill_contours(
+        frame,
+        canvas_width,
+        canvas_height,
+        contours,
+        fill_rule,
+        paint,
+    );
+}
+
+/// Draws an outline of a regular polygon to a frame of pixels.
+///
+/// The rotation is in radians. With a rotation of 0, the first corner points straight up. Nothing is drawn for fewer than 3 sides,
+/// and more than 4096 sides are drawn as 4096.
+///
+/// # Example
+///
+/// ```no_run
+/// use pixels::{Pixels, SurfaceTexture};
+/// use winit::dpi::LogicalSize;
+/// use winit::event_loop::{EventLoop};
+/// use std::error::Error;
+/// use winit::window::WindowBuilder;
+///
+/// const WIDTH: i32 = 800;
+/// const HEIGHT: i32 = 800;
+///
+/// fn main() -> Result<(), Box<dyn Error>> {
+///     let event_loop = EventLoop::new();
+///     let window = {
+///     let size = LogicalSize::new(WIDTH as f64, HEIGHT as f64);
+///     WindowBuilder::new()
+///         .with_title("Regular Polygon Example")
+///         .with_inner_size(size)
+///         .with_min_inner_size(size)
+///         .build(&event_loop)
+///         .unwrap()
+///     };
+///
+///     let mut pixels = {
+///         let window_size = window.inner_size();
+///         let surface_texture = SurfaceTexture::new(window_size.width, window_size.height, &window);
+///         Pixels::new(WIDTH as u32, HEIGHT as u32, surface_texture)?
+///     };
+///
+///     pixels_primitives::regular_polygon(
+///         pixels.get_frame(),
+///         WIDTH,
+///         400.0,
+///         400.0,
+///         150.0,
+///         6,
+///         0.0,
+///         &[255, 255, 255, 255],
+///     );
+///
+///     // Run your event loop here!
+///
+///     Ok(())
+/// }
+///
+/// ```
+#[warn(missing_docs)]
+#[allow(clippy::too_many_arguments)]
+pub fn regular_polygon(
+    frame: &mut [u8],
+    canvas_width: i32,
+    center_x: f64,
+    center_y: f64,
+    radius: f64,
+    sides: u32,
+    rotation: f64,
+    rgba: &[u8; 4],
+) {
+    if sides < 3 {
+        return;
+    }
+
+    let corners = math::regular_polygon_points(center_x, center_y, radius, sides, rotation);
+    polygon(frame, canvas_width, &corners, rgba);
+}
+
+/// Draws a filled regular polygon to a frame of pixels.
+///
+/// The rotation is in radians. With a rotation of 0, the first corner points straight up. Nothing is drawn for fewer than 3 sides,
+/// and more than 4096 sides are drawn as 4096.
+///
+/// # Example
+///
+/// ```no_run
+/// use pixels::{Pixels, SurfaceTexture};
+/// use winit::dpi::LogicalSize;
+/// use winit::event_loop::{EventLoop};
+/// use std::error::Error;
+/// use winit::window::WindowBuilder;
+///
+/// const WIDTH: i32 = 800;
+/// const HEIGHT: i32 = 800;
+///
+/// fn main() -> Result<(), Box<dyn Error>> {
+///     let event_loop = EventLoop::new();
+///     let window = {
+///     let size = LogicalSize::new(WIDTH as f64, HEIGHT as f64);
+///     WindowBuilder::new()
+///         .with_title("Filled Regular Polygon Example")
+///         .with_inner_size(size)
+///         .with_min_inner_size(size)
+///         .build(&event_loop)
+///         .unwrap()
+///     };
+///
+///     let mut pixels = {
+///         let window_size = window.inner_size();
+///         let surface_texture = SurfaceTexture::new(window_size.width, window_size.height, &window);
+///         Pixels::new(WIDTH as u32, HEIGHT as u32, surface_texture)?
+///     };
+///
+///     pixels_primitives::regular_polygon_filled(
+///         pixels.get_frame(),
+///         WIDTH,
+///         400.0,
+///         400.0,
+///         150.0,
+///         6,
+///         0.0,
+///         &[255, 255, 255, 255],
+///     );
+///
+///     // Run your event loop here!
+///
+///     Ok(())
+/// }
+///
+/// ```
+#[warn(missing_docs)]
+#[allow(clippy::too_many_arguments)]
+pub fn regular_polygon_filled<P: Paint + ?Sized>(
+    frame: &mut [u8],
+    canvas_width: i32,
+    center_x: f64,
+    center_y: f64,
+    radius: f64,
+    sides: u32,
+    rotation: f64,
+    paint: &P,
+) {
+    if sides < 3 {
+        return;
+    }
+
+    let corners = math::regular_polygon_points(center_x, center_y, radius, sides, rotation);
+    polygon_filled(frame, canvas_width, &corners, FillRule::NonZero, paint);
+}
+
+/// Draws an outline of a star to a frame of pixels.
+///
+/// The rotation is in radians. With a rotation of 0, the first point of the star points straight up.
+/// Nothing is drawn for fewer than 2 points, and more than 2048 points are drawn as 2048.
+///
+/// # Example
+///
+/// ```no_run
+/// use pixels::{Pixels, SurfaceTexture};
+/// use winit::dpi::LogicalSize;
+/// use winit::event_loop::{EventLoop};
+/// use std::error::Error;
+/// use winit::window::WindowBuilder;
+///
+/// const WIDTH: i32 = 800;
+/// const HEIGHT: i32 = 800;
+///
+/// fn main() -> Result<(), Box<dyn Error>> {
+///     let event_loop = EventLoop::new();
+///     let window = {
+///     let size = LogicalSize::new(WIDTH as f64, HEIGHT as f64);
+///     WindowBuilder::new()
+///         .with_title("Star Example")
+///         .with_inner_size(size)
+///         .with_min_inner_size(size)
+///         .build(&event_loop)
+///         .unwrap()
+///     };
+///
+///     let mut pixels = {
+///         let window_size = window.inner_size();
+///         let surface_texture = SurfaceTexture::new(window_size.width, window_size.height, &window);
+///         Pixels::new(WIDTH as u32, HEIGHT as u32, surface_texture)?
+///     };
+///
+///     pixels_primitives::star(
+///         pixels.get_frame(),
+///         WIDTH,
+///         400.0,
+///         400.0,
+///         150.0,
+///         60.0,
+///         5,
+///         0.0,
+///         &[255, 255, 255, 255],
+///     );
+///
+///     // Run your event loop here!
+///
+///     Ok(())
+/// }
+///
+/// ```
+#[warn(missing_docs)]
+#[allow(clippy::too_many_arguments)]
+pub fn star(
+    frame: &mut [u8],
+    canvas_width: i32,
+    center_x: f64,
+    center_y: f64,
+    outer_radius: f64,
+    inner_radius: f64,
+    points: u32,
+    rotation: f64,
+    rgba: &[u8; 4],
+) {
+    if points < 2 {
+        return;
+    }
+
+    let corners = math::star_points(
+        center_x,
+        center_y,
+        outer_radius,
+        inner_radius,
+        points,
+        rotation,
+    );
+    polygon(frame, canvas_width, &corners, rgba);
+}
+
+/// Draws a filled star to a frame of pixels.
+///
+/// The rotation is in radians. With a rotation of 0, the first point of the star points straight up.
+/// Nothing is drawn for fewer than 2 points, and more than 2048 points are drawn as 2048.
+///
+/// # Example
+///
+/// ```no_run
+/// use pixels::{Pixels, SurfaceTexture};
+/// use winit::dpi::LogicalSize;
+/// use winit::event_loop::{EventLoop};
+/// use std::error::Error;
+/// use winit::window::WindowBuilder;
+///
+/// const WIDTH: i32 = 800;
+/// const HEIGHT: i32 = 800;
+///
+/// fn main() -> Result<(), Box<dyn Error>> {
+///     let event_loop = EventLoop::new();
+///     let window = {
+///     let size = LogicalSize::new(WIDTH as f64, HEIGHT as f64);
+///     WindowBuilder::new()
+///         .with_title("Filled Star Example")
+///         .with_inner_size(size)
+///         .with_min_inner_size(size)
+///         .build(&event_loop)
+///         .unwrap()
+///     };
+///
+///     let mut pixels = {
+///         let window_size = window.inner_size();
+///         let surface_texture = SurfaceTexture::new(window_size.width, window_size.height, &window);
+///         Pixels::new(WIDTH as u32, HEIGHT as u32, surface_texture)?
+///     };
+///
+///     pixels_primitives::star_filled(
+///         pixels.get_frame(),
+///         WIDTH,
+///         400.0,
+///         400.0,
+///         150.0,
+///         60.0,
+///         5,
+///         0.0,
+///         &[255, 255, 255, 255],
+///     );
+///
+///     // Run your event loop here!
+///
+///     Ok(())
+/// }
+///
+/// ```
+#[warn(missing_docs)]
+#[allow(clippy::too_many_arguments)]
+pub fn star_filled<P: Paint + ?Sized>(
+    frame: &mut [u8],
+    canvas_width: i32,
+    center_x: f64,
+    center_y: f64,
+    outer_radius: f64,
+    inner_radius: f64,
+    points: u32,
+    rotation: f64,
+    paint: &P,
+) {
+    if points < 2 {
+        return;
+    }
+
+    let corners = math::star_points(
+        center_x,
+        center_y,
+        outer_radius,
+        inner_radius,
+        points,
+        rotation,
+    );
+    polygon_filled(frame, canvas_width, &corners, FillRule::NonZero, paint);
+}
+
+/// Draws a quadratic Bézier curve to a frame of pixels.
+///
+/// The curve is split into straight lines until no part of it is further than `tolerance` pixels away from the real curve,
+/// so zooming in only needs a smaller tolerance. The lines are drawn the same way as [`line`](fn@line).
+/// Tolerances below 0.01 are raised to 0.01, and a NaN tolerance is treated as 0.25.
+///
+/// # Example
+///
+/// ```no_run
+/// use pixels::{Pixels, SurfaceTexture};
+/// use winit::dpi::LogicalSize;
+/// use winit::event_loop::{EventLoop};
+/// use std::error::Error;
+/// use winit::window::WindowBuilder;
+///
+/// const WIDTH: i32 = 800;
+/// const HEIGHT: i32 = 800;
+///
+/// fn main() -> Result<(), Box<dyn Error>> {
+///     let event_loop = EventLoop::new();
+///     let window = {
+///     let size = LogicalSize::new(WIDTH as f64, HEIGHT as f64);
+///     WindowBuilder::new()
+///         .with_title("Quadratic Bezier Example")
+///         .with_inner_size(size)
+///         .with_min_inner_size(size)
+///         .build(&event_loop)
+///         .unwrap()
+///     };
+///
+///     let mut pixels = {
+///         let window_size = window.inner_size();
+///         let surface_texture = SurfaceTexture::new(window_size.width, window_size.height, &window);
+///         Pixels::new(WIDTH as u32, HEIGHT as u32, surface_texture)?
+///     };
+///
+///     pixels_primitives::quad_bezier(
+///         pixels.get_frame(),
+///         WIDTH,
+///         100.0,
+///         600.0,
+///         400.0,
+///         100.0,
+///         700.0,
+///         600.0,
+///         0.25,
+///         &[255, 255, 255, 255],
+///     );
+///
+///     // Run your event loop here!
+///
+///     Ok(())
+/// }
+///
+/// ```
+#[warn(missing_docs)]
+#[allow(clippy::too_many_arguments)]
+pub fn quad_bezier(
+    frame: &mut [u8],
+    canvas_width: i32,
+    starting_x: f64,
+    starting_y: f64,
+    control_x: f64,
+    control_y: f64,
+    ending_x: f64,
+    ending_y: f64,
+    tolerance: f64,
+    rgba: &[u8; 4],
+) {
+    let mut points = vec![(starting_x, starting_y)];
+    curve::flatten_quad(
+        (starting_x, starting_y),
+        (control_x, control_y),
+        (ending_x, ending_y),
+        tolerance,
+        &mut points,
+    );
+    polyline(frame, canvas_width, &points, rgba);
+}
+
+/// Draws a cubic Bézier curve to a frame of pixels.
+///
+/// The curve is split into straight lines until no part of it is further than `tolerance` pixels away from the real curve,
+/// so zooming in only needs a smaller tolerance. The lines are drawn the same way as [`line`](fn@line).
+/// Tolerances below 0.01 are raised to 0.01, and a NaN tolerance is treated as 0.25.
+///
+/// # Example
+///
+/// ```no_run
+/// use pixels::{Pixels, SurfaceTexture};
+/// use winit::dpi::LogicalSize;
+/// use winit::event_loop::{EventLoop};
+/// use std::error::Error;
+/// use winit::window::WindowBuilder;
+///
+/// const WIDTH: i32 = 800;
+/// const HEIGHT: i32 = 800;
+///
+/// fn main() -> Result<(), Box<dyn Error>> {
+///     let event_loop = EventLoop::new();
+///     let window = {
+///     let size = LogicalSize::new(WIDTH as f64, HEIGHT as f64);
+///     WindowBuilder::new()
+///         .with_title("Cubic Bezier Example")
+///         .with_inner_size(size)
+///         .with_min_inner_size(size)
+///         .build(&event_loop)
+///         .unwrap()
+///     };
+///
+///     let mut pixels = {
+///         let window_size = window.inner_size();
+///         let surface_texture = SurfaceTexture::new(window_size.width, window_size.height, &window);
+///         Pixels::new(WIDTH as u32, HEIGHT as u32, surface_texture)?
+///     };
+///
+///     pixels_primitives::cubic_bezier(
+///         pixels.get_frame(),
+///         WIDTH,
+///         100.0,
+///         400.0,
+///         300.0,
+///         100.0,
+///         500.0,
+///         700.0,
+///         700.0,
+///         400.0,
+///         0.25,
+///         &[255, 255, 255, 255],
+///     );
+///
+///     // Run your event loop here!
+///
+///     Ok(())
+/// }
+///
+/// ```
+#[warn(missing_docs)]
+#[allow(clippy::too_many_arguments)]
+pub fn cubic_bezier(
+    frame: &mut [u8],
+    canvas_width: i32,
+    starting_x: f64,
+    starting_y: f64,
+    first_control_x: f64,
+    first_control_y: f64,
+    second_control_x: f64,
+    second_control_y: f64,
+    ending_x: f64,
+    ending_y: f64,
+    tolerance: f64,
+    rgba: &[u8; 4],
+) {
+    let mut points = vec![(starting_x, starting_y)];
+    curve::flatten_cubic(
+        (starting_x, starting_y),
+        (first_control_x, first_control_y),
+        (second_control_x, second_control_y),
+        (ending_x, ending_y),
+        tolerance,
+        &mut points,
+    );
+    polyline(frame, canvas_width, &points, rgba);
+}
+
+/// Draws a smooth Catmull-Rom curve through every point to a frame of pixels.
+///
+/// `tension` controls how tightly the curve bends at each point. A tension of 0 gives a classic Catmull-Rom curve,
+/// and a tension of 1 pulls the curve into straight lines between the points. The curve is flattened into lines the same way as
+/// [`cubic_bezier`], using `tolerance`.
+///
+/// # Example
+///
+/// ```no_run
+/// use pixels::{Pixels, SurfaceTexture};
+/// use winit::dpi::LogicalSize;
+/// use winit::event_loop::{EventLoop};
+/// use std::error::Error;
+/// use winit::window::WindowBuilder;
+///
+/// const WIDTH: i32 = 800;
+/// const HEIGHT: i32 = 800;
+///
+/// fn main() -> Result<(), Box<dyn Error>> {
+///     let event_loop = EventLoop::new();
+///     let window = {
+///     let size = LogicalSize::new(WIDTH as f64, HEIGHT as f64);
+///     WindowBuilder::new()
+///         .with_title("Catmull-Rom Example")
+///         .with_inner_size(size)
+///         .with_min_inner_size(size)
+///         .build(&event_loop)
+///         .unwrap()
+///     };
+///
+///     let mut pixels = {
+///         let window_size = window.inner_size();
+///         let surface_texture = SurfaceTexture::new(window_size.width, window_size.height, &window);
+///         Pixels::new(WIDTH as u32, HEIGHT as u32, surface_texture)?
+///     };
+///
+///     pixels_primitives::catmull_rom(
+///         pixels.get_frame(),
+///         WIDTH,
+///         &[(100.0, 400.0), (250.0, 200.0), (400.0, 450.0), (550.0, 300.0), (700.0, 350.0)],
+///         0.0,
+///         0.25,
+///         &[255, 255, 255, 255],
+///     );
+///
+///     // Run your event loop here!
+///
+///     Ok(())
+/// }
+///
+/// ```
+#[warn(missing_docs)]
+pub fn catmull_rom(
+    frame: &mut [u8],
+    canvas_width: i32,
+    points: &[(f64, f64)],
+    tension: f64,
+    tolerance: f64,
+    rgba: &[u8; 4],
+) {
+    if points.len() < 2 {
+        return;
+    }
+
+    let mut flattened = vec![points[0]];
+    let scale = (1.0 - tension) / 6.0;
+    for i in 0..points.len() - 1 {
+        // The first and last points are repeated, so the curve starts and ends on them.
+        let before = points[i.saturating_sub(1)];
+        let (start, end) = (points[i], points[i + 1]);
+        let after = points[(i + 2).min(points.len() - 1)];
+
+        curve::flatten_cubic(
+            start,
+            (
+                start.0 + (end.0 - before.0) * scale,
+                start.1 + (end.1 - before.1) * scale,
+            ),
+            (
+                end.0 - (after.0 - start.0) * scale,
+                end.1 - (after.1 - start.1) * scale,
+            ),
+            end,
+            tolerance,
+            &mut flattened,
+        );
+    }
+    polyline(frame, canvas_width, &flattened, rgba);
+}
+
+/// Draws a smooth uniform cubic B-spline shaped by the control points to a frame of pixels.
+///
+/// Unlike [`catmull_rom`], the curve only passes through the first and last points and is pulled towards the others,
+/// which smooths out noisy points. The curve is flattened into lines the same way as [`cubic_bezier`], using `tolerance`.
+///
+/// # Example
+///
+/// ```no_run
+/// use pixels::{Pixels, SurfaceTexture};
+/// use winit::dpi::LogicalSize;
+/// use winit::event_loop::{EventLoop};
+/// use std::error::Error;
+/// use winit::window::WindowBuilder;
+///
+/// const WIDTH: i32 = 800;
+/// const HEIGHT: i32 = 800;
+///
+/// fn main() -> Result<(), Box<dyn Error>> {
+///     let event_loop = EventLoop::new();
+///     let window = {
+///     let size = LogicalSize::new(WIDTH as f64, HEIGHT as f64);
+///     WindowBuilder::new()
+///         .with_title("B-Spline Example")
+///         .with_inner_size(size)
+///         .with_min_inner_size(size)
+///         .build(&event_loop)
+///         .unwrap()
+///     };
+///
+///     let mut pixels = {
+///         let window_size = window.inner_size();
+///         let surface_texture = SurfaceTexture::new(window_size.width, window_size.height, &window);
+///         Pixels::new(WIDTH as u32, HEIGHT as u32, surface_texture)?
+///     };
+///
+///     pixels_primitives::b_spline(
+///         pixels.get_frame(),
+///         WIDTH,
+///         &[(100.0, 400.0), (250.0, 200.0), (400.0, 450.0), (550.0, 300.0), (700.0, 350.0)],
+///         0.25,
+///         &[255, 255, 255, 255],
+///     );
+///
+///     // Run your event loop here!
+///
+///     Ok(())
+/// }
+///
+/// ```
+#[warn(missing_docs)]
+pub fn b_spline(
+    frame: &mut [u8],
+    canvas_width: i32,
+    points: &[(f64, f64)],
+    tolerance: f64,
+    rgba: &[u8; 4],
+) {
+    if points.len() < 2 {
+        return;
+    }
+
+    // Repeating the end points three times makes the curve start and end on them.
+    let first = points[0];
+    let last = points[points.len() - 1];
+    let clamped: Vec<(f64, f64)> = [first, first]
+        .into_iter()
+        .chain(points.iter().copied())
+        .chain([last, last])
+        .collect();
+
+    let mut flattened = vec![first];
+    for window in clamped.windows(4) {
+        let [p0, p1, p2, p3] = [window[0], window[1], window[2], window[3]];
+        curve::flatten_cubic(
+            (
+                (p0.0 + 4.0 * p1.0 + p2.0) / 6.0,
+                (p0.1 + 4.0 * p1.1 + p2.1) / 6.0,
+            ),
+            ((2.0 * p1.0 + p2.0) / 3.0, (2.0 * p1.1 + p2.1) / 3.0),
+            ((p1.0 + 2.0 * p2.0) / 3.0, (p1.1 + 2.0 * p2.1) / 3.0),
+            (
+                (p1.0 + 4.0 * p2.0 + p3.0) / 6.0,
+                (p1.1 + 4.0 * p2.1 + p3.1) / 6.0,
+            ),
+            tolerance,
+            &mut flattened,
+        );
+    }
+    polyline(frame, canvas_width, &flattened, rgba);
+}
+
+/// Draws an image, or part of one, into a frame of pixels.
+///
+/// The top left corner of the image is placed at (x, y), and anything that falls outside of the frame is clipped.
+/// See [`BlitOptions`] for drawing only part of the image, alpha blending, color keying, flipping and rotating.
+///
+/// # Example
+///
+/// ```no_run
+/// use pixels::{Pixels, SurfaceTexture};
+/// use winit::dpi::LogicalSize;
+/// use winit::event_loop::{EventLoop};
+/// use std::error::Error;
+/// use winit::window::WindowBuilder;
+/// use pixels_primitives::{BlitOptions, Image};
+///
+/// const WIDTH: i32 = 800;
+/// const HEIGHT: i32 = 800;
+///
+/// fn main() -> Result<(), Box<dyn Error>> {
+///     let event_loop = EventLoop::new();
+///     let window = {
+///     let size = LogicalSize::new(WIDTH as f64, HEIGHT as f64);
+///     WindowBuilder::new()
+///         .with_title("Blit Example")
+///         .with_inner_size(size)
+///         .with_min_inner_size(size)
+///         .build(&event_loop)
+///         .unwrap()
+///     };
+///
+///     let mut pixels = {
+///         let window_size = window.inner_size();
+///         let surface_texture = SurfaceTexture::new(window_size.width, window_size.height, &window);
+///         Pixels::new(WIDTH as u32, HEIGHT as u32, surface_texture)?
+///     };
+///
+///     // A small sprite with a magenta background that should not be drawn.
+///     let mut sprite = Image::new(16, 16);
+///     for pixel in sprite.pixels_mut().chunks_exact_mut(4) {
+///         pixel.copy_from_slice(&[255, 0, 255, 255]);
+///     }
+///     pixels_primitives::circle_filled(sprite.pixels_mut(), 16, 8.0, 8.0, 6.0, &[255, 255, 0, 255]);
+///
+///     pixels_primitives::blit(
+///         pixels.get_frame(),
+///         WIDTH,
+///         &sprite,
+///         200,
+///         200,
+///         &BlitOptions {
+///             color_key: Some([255, 0, 255]),
+///             ..BlitOptions::default()
+///         },
+///     );
+///
+///     // Run your event loop here!
+///
+///     Ok(())
+/// }
+///
+/// ```
+#[warn(missing_docs)]
+pub fn blit(
+    frame: &mut [u8],
+    canvas_width: i32,
+    image: &Image,
+    x: i32,
+    y: i32,
+    options: &BlitOptions,
+) {
+    let canvas_height = frame.len() as i32 / 4 / canvas_width;
+    blit::blit(frame, canvas_width, canvas_height, image, x, y, options);
+}
+
+/// Draws an image, or part of one, stretched to fill a rectangle of a frame of pixels.
+///
+/// The image can be scaled up or down by any factor, and differently in each direction. [`Filter::Nearest`] keeps
+/// pixel art crisp, while [`Filter::Bilinear`] smoothly blends between pixels. Only pixels inside of the source region are
+/// sampled, so neighbouring sprites on a sheet never bleed in. Anything that falls outside of the frame is clipped.
+///
+/// # Example
+///
+/// ```no_run
+/// use pixels::{Pixels, SurfaceTexture};
+/// use winit::dpi::LogicalSize;
+/// use winit::event_loop::{EventLoop};
+/// use std::error::Error;
+/// use winit::window::WindowBuilder;
+/// use pixels_primitives::{BlitOptions, Filter, Image, Region};
+///
+/// const WIDTH: i32 = 800;
+/// const HEIGHT: i32 = 800;
+///
+/// fn main() -> Result<(), Box<dyn Error>> {
+///     let event_loop = EventLoop::new();
+///     let window = {
+///     let size = LogicalSize::new(WIDTH as f64, HEIGHT as f64);
+///     WindowBuilder::new()
+///         .with_title("Scaled Blit Example")
+///         .with_inner_size(size)
+///         .with_min_inner_size(size)
+///         .build(&event_loop)
+///         .unwrap()
+///     };
+///
+///     let mut pixels = {
+///         let window_size = window.inner_size();
+///         let surface_texture = SurfaceTexture::new(window_size.width, window_size.height, &window);
+///         Pixels::new(WIDTH as u32, HEIGHT as u32, surface_texture)?
+///     };
+///
+///     // A 2x2 image, blown up to 200x200 pixels.
+///     let image = Image::from_rgba(
+///         2,
+///         2,
+///         vec![
+///             255, 0, 0, 255, 0, 255, 0, 255,
+///             0, 0, 255, 255, 255, 255, 255, 255,
+///         ],
+///     );
+///
+///     pixels_primitives::blit_scaled(
+///         pixels.get_frame(),
+///         WIDTH,
+///         &image,
+///         Region::new(100, 100, 200, 200),
+///         Filter::Nearest,
+///         &BlitOptions::default(),
+///     );
+///
+///     pixels_primitives::blit_scaled(
+///         pixels.get_frame(),
+///         WIDTH,
+///         &image,
+///         Region::new(400, 100, 200, 200),
+///         Filter::Bilinear,
+///         &BlitOptions::default(),
+///     );
+///
+///     // Run your event loop here!
+///
+///     Ok(())
+/// }
+///
+/// ```
+#[warn(missing_docs)]
+pub fn blit_scaled(
+    frame: &mut [u8],
+    canvas_width: i32,
+    image: &Image,
+    destination: Region,
+    filter: Filter,
+    options: &BlitOptions,
+) {
+    let canvas_height = frame.len() as i32 / 4 / canvas_width;
+    blit::blit_scaled(
+        frame,
+        canvas_width,
+        canvas_height,
+        image,
+        destination,
+        filter,
+        options,
+    );
+}
+
+/// Draws an image, or part of one, with an affine transform applied to it into a frame of pixels.
+///
+/// The transform maps the image onto the frame, with (0, 0) being the top left corner of the source region. Every
+/// pixel the transformed image covers is mapped back into the image and sampled with `filter`, so rotated, scaled and skewed
+/// images have no holes. The edges and clipping are handled by the same rasterizer as [`polygon_filled`].
+/// Nothing is drawn if the transform squashes the image flat.
+///
+/// # Example
+///
+/// ```no_run
+/// use pixels::{Pixels, SurfaceTexture};
+/// use winit::dpi::LogicalSize;
+/// use winit::event_loop::{EventLoop};
+/// use std::error::Error;
+/// use winit::window::WindowBuilder;
+/// use pixels_primitives::{BlitOptions, Filter, Image, Transform};
+///
+/// const WIDTH: i32 = 800;
+/// const HEIGHT: i32 = 800;
+///
+/// fn main() -> Result<(), Box<dyn Error>> {
+///     let event_loop = EventLoop::new();
+///     let window = {
+///     let size = LogicalSize::new(WIDTH as f64, HEIGHT as f64);
+///     WindowBuilder::new()
+///         .with_title("Transformed Blit Example")
+///         .with_inner_size(size)
+///         .with_min_inner_size(size)
+///         .build(&event_loop)
+///         .unwrap()
+///     };
+///
+///     let mut pixels = {
+///         let window_size = window.inner_size();
+///         let surface_texture = SurfaceTexture::new(window_size.width, window_size.height, &window);
+///         Pixels::new(WIDTH as u32, HEIGHT as u32, surface_texture)?
+///     };
+///
+///     let mut sprite = Image::new(64, 32);
+///     pixels_primitives::rect_filled(sprite.pixels_mut(), 64, 0, 0, 63, 31, &[255, 0, 0, 255]);
+///     pixels_primitives::rect_filled(sprite.pixels_mut(), 64, 48, 0, 63, 31, &[255, 255, 255, 255]);
+///
+///     // Rotate the sprite around its center, then move that center to (400, 400).
+///     let transform = Transform::translate(-32.0, -16.0)
+///         .then(&Transform::rotate(std::f64::consts::FRAC_PI_6))
+///         .then(&Transform::scale(3.0, 3.0))
+///         .then(&Transform::translate(400.0, 400.0));
+///
+///     pixels_primitives::blit_transformed(
+///         pixels.get_frame(),
+///         WIDTH,
+///         &sprite,
+///         &transform,
+///         Filter::Bilinear,
+///         &BlitOptions::default(),
+///     );
+///
+///     // Run your event loop here!
+///
+///     Ok(())
+/// }
+///
+/// ```
+#[warn(missing_docs)]
+pub fn blit_transformed(
+    frame: &mut [u8],
+    canvas_width: i32,
+    image: &Image,
+    transform: &Transform,
+    filter: Filter,
+    options: &BlitOptions,
+) {
+    let canvas_height = frame.len() as i32 / 4 / canvas_width;
+    blit::blit_transformed(
+        frame,
+        canvas_width,
+        canvas_height,
+        image,
+        transform,
+        filter,
+        options,
+    );
+}
+
+/// Draws a bordered image, or part of one, stretched to fill a rectangle of a frame of pixels without scaling its corners.
+///
+/// The image is cut into nine slices by the insets of `slice`. The corners are drawn at their original size, while the
+/// edges and the center are stretched or tiled to fill the rest of the destination, which makes it a good fit for skinned UI
+/// panels and dialog boxes drawn on top of [`rect_filled`] backgrounds. If the destination is smaller than the corners, they
+/// are shrunk to fit. The flips and rotation of `options` are not applied.
+///
+/// # Example
+///
+/// ```no_run
+/// use pixels::{Pixels, SurfaceTexture};
+/// use winit::dpi::LogicalSize;
+/// use winit::event_loop::{EventLoop};
+/// use std::error::Error;
+/// use winit::window::WindowBuilder;
+/// use pixels_primitives::{BlitOptions, Image, NineSlice, Region};
+///
+/// const WIDTH: i32 = 800;
+/// const HEIGHT: i32 = 800;
+///
+/// fn main() -> Result<(), Box<dyn Error>> {
+///     let event_loop = EventLoop::new();
+///     let window = {
+///     let size = LogicalSize::new(WIDTH as f64, HEIGHT as f64);
+///     WindowBuilder::new()
+///         .with_title("Nine Slice Example")
+///         .with_inner_size(size)
+///         .with_min_inner_size(size)
+///         .build(&event_loop)
+///         .unwrap()
+///     };
+///
+///     let mut pixels = {
+///         let window_size = window.inner_size();
+///         let surface_texture = SurfaceTexture::new(window_size.width, window_size.height, &window);
+///         Pixels::new(WIDTH as u32, HEIGHT as u32, surface_texture)?
+///     };
+///
+///     // A 24x24 panel with an 8 pixel white border around a blue center.
+///     let mut panel = Image::new(24, 24);
+///     pixels_primitives::rect_filled(panel.pixels_mut(), 24, 0, 0, 23, 23, &[255, 255, 255, 255]);
+///     pixels_primitives::rect_filled(panel.pixels_mut(), 24, 8, 8, 15, 15, &[40, 60, 160, 255]);
+///
+///     pixels_primitives::nine_slice(
+///         pixels.get_frame(),
+///         WIDTH,
+///         &panel,
+///         &NineSlice::uniform(8),
+///         Region::new(100, 100, 400, 250),
+///         &BlitOptions::default(),
+///     );
+///
+///     // Run your event loop here!
+///
+///     Ok(())
+/// }
+///
+/// ```
+#[warn(missing_docs)]
+pub fn nine_slice(
+    frame: &mut [u8],
+    canvas_width: i32,
+    image: &Image,
+    slice: &NineSlice,
+    destination: Region,
+    options: &BlitOptions,
+) {
+    let canvas_height = frame.len() as i32 / 4 / canvas_width;
+    nine_slice::nine_slice(
+        frame,
+        canvas_width,
+        canvas_height,
+        image,
+        slice,
+        destination,
+        options,
+    );
+}
+
+/// Fills the connected region of similar colors around a seed pixel, like the bucket tool of a paint program.
+///
+/// A pixel is part of the region if none of its channels differ from the seed pixel's color by more than `tolerance`,
+/// and it is connected to the seed through other pixels in the region. Nothing happens if the seed is outside of the frame.
+///
+/// # Example
+///
+/// ```no_run
+/// use pixels::{Pixels, SurfaceTexture};
+/// use winit::dpi::LogicalSize;
+/// use winit::event_loop::{EventLoop};
+/// use std::error::Error;
+/// use winit::window::WindowBuilder;
+///
+/// const WIDTH: i32 = 800;
+/// const HEIGHT: i32 = 800;
+///
+/// fn main() -> Result<(), Box<dyn Error>> {
+///     let event_loop = EventLoop::new();
+///     let window = {
+///     let size = LogicalSize::new(WIDTH as f64, HEIGHT as f64);
+///     WindowBuilder::new()
+///         .with_title("Flood Fill Example")
+///         .with_inner_size(size)
+///         .with_min_inner_size(size)
+///         .build(&event_loop)
+///         .unwrap()
+///     };
+///
+///     let mut pixels = {
+///         let window_size = window.inner_size();
+///         let surface_texture = SurfaceTexture::new(window_size.width, window_size.height, &window);
+///         Pixels::new(WIDTH as u32, HEIGHT as u32, surface_texture)?
+///     };
+///
+///     pixels_primitives::circle(
+///         pixels.get_frame(),
+///         WIDTH,
+///         400.0,
+///         400.0,
+///         200.0,
+///         2.0,
+///         &[255, 255, 255, 255],
+///     );
+///
+///     pixels_primitives::flood_fill(
+///         pixels.get_frame(),
+///         WIDTH,
+///         400,
+///         400,
+///         pixels_primitives::Connectivity::Four,
+///         0,
+///         &[255, 0, 0, 255],
+///     );
+///
+///     // Run your event loop here!
+///
+///     Ok(())
+/// }
+///
+/// ```
+#[warn(missing_docs)]
+pub fn flood_fill(
+    frame: &mut [u8],
+    canvas_width: i32,
+    seed_x: i32,
+    seed_y: i32,
+    connectivity: Connectivity,
+    tolerance: u8,
+    rgba: &[u8; 4],
+) {
+    let canvas_height = frame.len() as i32 / 4 / canvas_width;
+    flood::fill(
+        frame,
+        canvas_width,
+        canvas_height,
+        (seed_x, seed_y),
+        connectivity,
+        tolerance,
+        rgba,
+    );
+}
+
+/// Reads the color of a pixel of a frame of pixels, or `None` if it is outside of the frame.
+///
+/// # Example
+///
+/// ```no_run
+/// use pixels::{Pixels, SurfaceTexture};
+/// use winit::dpi::LogicalSize;
+/// use winit::event_loop::{EventLoop};
+/// use std::error::Error;
+/// use winit::window::WindowBuilder;
+///
+/// const WIDTH: i32 = 800;
+/// const HEIGHT: i32 = 800;
+///
+/// fn main() -> Result<(), Box<dyn Error>> {
+///     let event_loop = EventLoop::new();
+///     let window = {
+///     let size = LogicalSize::new(WIDTH as f64, HEIGHT as f64);
+///     WindowBuilder::new()
+///         .with_title("Get Pixel Example")
+///         .with_inner_size(size)
+///         .with_min_inner_size(size)
+///         .build(&event_loop)
+///         .unwrap()
+///     };
+///
+///     let mut pixels = {
+///         let window_size = window.inner_size();
+///         let surface_texture = SurfaceTexture::new(window_size.width, window_size.height, &window);
+///         Pixels::new(WIDTH as u32, HEIGHT as u32, surface_texture)?
+///     };
+///
+///     pixels_primitives::circle_filled(pixels.get_frame(), WIDTH, 400.0, 400.0, 50.0, &[255, 0, 0, 255]);
+///
+///     // An eyedropper that picks up the red of the circle under the cursor.
+///     if let Some(picked) = pixels_primitives::get_pixel(pixels.get_frame(), WIDTH, 400, 400) {
+///         pixels_primitives::rect_filled(pixels.get_frame(), WIDTH, 10, 10, 40, 40, &picked);
+///     }
+///
+///     // Run your event loop here!
+///
+///     Ok(())
+/// }
+///
+/// ```
+#[warn(missing_docs)]
+pub fn get_pixel(frame: &[u8], canvas_width: i32, x: i32, y: i32) -> Option<[u8; 4]> {
+    let canvas_height = frame.len() as i32 / 4 / canvas_width.max(1);
+    if (x < 0) || (y < 0) || (x >= canvas_width) || (y >= canvas_height) {
+        return None;
+    }
+    let index = get_starting_pixel_index(x, y, canvas_width);
+    let mut rgba = [0; 4];
+    rgba.copy_from_slice(&frame[index..index + 4]);
+    Some(rgba)
+}
+
+/// Reads the color of a pixel of a frame of pixels, using the closest pixel on the edge of the frame for positions outside of it.
+///
+/// This is useful for filters like blurs, which read the pixels around a position without having to check the bounds
+/// themselves. `None` is only returned when the frame doesn't have any pixels.
+///
+/// # Example
+///
+/// ```no_run
+/// use pixels::{Pixels, SurfaceTexture};
+/// use winit::dpi::LogicalSize;
+/// use winit::event_loop::{EventLoop};
+/// use std::error::Error;
+/// use winit::window::WindowBuilder;
+///
+/// const WIDTH: i32 = 800;
+/// const HEIGHT: i32 = 800;
+///
+/// fn main() -> Result<(), Box<dyn Error>> {
+///     let event_loop = EventLoop::new();
+///     let window = {
+///     let size = LogicalSize::new(WIDTH as f64, HEIGHT as f64);
+///     WindowBuilder::new()
+///         .with_title("Sample Example")
+///         .with_inner_size(size)
+///         .with_min_inner_size(size)
+///         .build(&event_loop)
+///         .unwrap()
+///     };
+///
+///     let mut pixels = {
+///         let window_size = window.inner_size();
+///         let surface_texture = SurfaceTexture::new(window_size.width, window_size.height, &window);
+///         Pixels::new(WIDTH as u32, HEIGHT as u32, surface_texture)?
+///     };
+///
+///     pixels_primitives::rect_filled(pixels.get_frame(), WIDTH, 0, 0, WIDTH - 1, 10, &[0, 0, 255, 255]);
+///
+///     // Far above the frame, so the blue pixel in the top row is used.
+///     if let Some(color) = pixels_primitives::sample(pixels.get_frame(), WIDTH, 400, -1000) {
+///         pixels_primitives::circle_filled(pixels.get_frame(), WIDTH, 400.0, 400.0, 50.0, &color);
+///     }
+///
+///     // Run your event loop here!
+///
+///     Ok(())
+/// }
+///
+/// ```
+#[warn(missing_docs)]
+pub fn sample(frame: &[u8], canvas_width: i32, x: i32, y: i32) -> Option<[u8; 4]> {
+    let canvas_height = frame.len() as i32 / 4 / canvas_width.max(1);
+    if canvas_width <= 0 || canvas_height <= 0 {
+        return None;
+    }
+    get_pixel(
+        frame,
+        canvas_width,
+        x.clamp(0, canvas_width - 1),
+        y.clamp(0, canvas_height - 1),
+    )
+}
+
+/// Copies a rectangle of a frame of pixels into a new image.
+///
+/// The image is always the size of the region. Parts of the region that are outside of the frame are left transparent in
+/// the image, so it can be pasted back at the region's position without shifting.
+///
+/// # Example
+///
+/// ```no_run
+/// use pixels::{Pixels, SurfaceTexture};
+/// use winit::dpi::LogicalSize;
+/// use winit::event_loop::{EventLoop};
+/// use std::error::Error;
+/// use winit::window::WindowBuilder;
+/// use pixels_primitives::{BlitOptions, Region};
+///
+/// const WIDTH: i32 = 800;
+/// const HEIGHT: i32 = 800;
+///
+/// fn main() -> Result<(), Box<dyn Error>> {
+///     let event_loop = EventLoop::new();
+///     let window = {
+///     let size = LogicalSize::new(WIDTH as f64, HEIGHT as f64);
+///     WindowBuilder::new()
+///         .with_title("Copy Region Example")
+///         .with_inner_size(size)
+///         .with_min_inner_size(size)
+///         .build(&event_loop)
+///         .unwrap()
+///     };
+///
+///     let mut pixels = {
+///         let window_size = window.inner_size();
+///         let surface_texture = SurfaceTexture::new(window_size.width, window_size.height, &window);
+///         Pixels::new(WIDTH as u32, HEIGHT as u32, surface_texture)?
+///     };
+///
+///     pixels_primitives::circle_filled(pixels.get_frame(), WIDTH, 100.0, 100.0, 50.0, &[0, 200, 0, 255]);
+///
+///     // Copy the circle and paste it somewhere else.
+///     let copy = pixels_primitives::copy_region(pixels.get_frame(), WIDTH, Region::new(50, 50, 101, 101));
+///     pixels_primitives::blit(pixels.get_frame(), WIDTH, &copy, 500, 500, &BlitOptions::default());
+///
+///     // Run your event loop here!
+///
+///     Ok(())
+/// }
+///
+/// ```
+#[warn(missing_docs)]
+pub fn copy_region(frame: &[u8], canvas_width: i32, region: Region) -> Image {
+    let mut image = Image::new(region.width, region.height);
+    let canvas_height = frame.len() as i32 / 4 / canvas_width.max(1);
+    let canvas = Region::new(
+        0,
+        0,
+        canvas_width.max(0) as u32,
+        canvas_height.max(0) as u32,
+    );
+    let Some(visible) = region.intersect(&canvas) else {
+        return image;
+    };
+
+    let row_length = visible.width as usize * 4;
+    for y in visible.y..visible.y + visible.height as i32 {
+        let start = get_starting_pixel_index(visible.x, y, canvas_width);
+        let image_start =
+            ((y - region.y) as usize * region.width as usize + (visible.x - region.x) as usize) * 4;
+        image.pixels_mut()[image_start..image_start + row_length]
+            .copy_from_slice(&frame[start..start + row_length]);
+    }
+    image
+}
+
+#[inline]
+fn get_starting_pixel_index(x: i32, y: i32, canvas_width: i32) -> usize {
+    (((y * canvas_width) + (x)) * 4) as usize
+}
+
+#[inline]
+fn color_position(
+    x: i32,
+    y: i32,
+    canvas_width: i32,
+    canvas_height: i32,
+    frame: &mut [u8],
+    rgba: &[u8],
+) {
+    if (x < 0) || (y < 0) || (x >= canvas_width) || (y >= canvas_height) {
+        return;
+    }
+    let index = get_starting_pixel_index(x, y, canvas_width);
+    let pixel = &mut frame[index..index + 4];
+    pixel.copy_from_slice(rgba);
+}
+
+#[inline]
+fn paint_position<P: Paint + ?Sized>(
+    x: i32,
+    y: i32,
+    canvas_width: i32,
+    canvas_height: i32,
+    frame: &mut [u8],
+    paint: &P,
+) {
+    if (x < 0) || (y < 0) || (x >= canvas_width) || (y >= canvas_height) {
+        return;
+    }
+    let index = get_starting_pixel_index(x, y, canvas_width);
+    paint.fill_span(&mut frame[index..index + 4], x, y);
+}
+
+/// Colors every pixel inside the inclusive box from (min_x, min_y) to (max_x, max_y).
+///
+/// The box is clipped to the canvas first, so only the rows and columns that are actually visible are touched.
+#[inline]
+#[allow(clippy::too_many_arguments)]
+fn fill_box<P: Paint + ?Sized>(
+    min_x: i32,
+    min_y: i32,
+    max_x: i32,
+    max_y: i32,
+    canvas_width: i32,
+    canvas_height: i32,
+    frame: &mut [u8],
+    paint: &P,
+) {
+    let (min_x, min_y) = (min_x.max(0), min_y.max(0));
+    let (max_x, max_y) = (max_x.min(canvas_width - 1), max_y.min(canvas_height - 1));
+    if (min_x > max_x) || (min_y > max_y) {
+        return;
+    }
+
+    for y in min_y..=max_y {
+        let start = get_starting_pixel_index(min_x, y, canvas_width);
+        let end = get_starting_pixel_index(max_x, y, canvas_width) + 4;
+        paint.fill_span(&mut frame[start..end], min_x, y);
+    }
+}
+
+/// Colors the pixels inside of the outer box that are not inside of the inner box.
+///
+/// Both boxes are inclusive and given as (min_x, min_y, max_x, max_y). If the inner box is empty, the whole outer box is filled.
+#[inline]
+fn fill_ring<P: Paint + ?Sized>(
+    outer: (i32, i32, i32, i32),
+    inner: (i32, i32, i32, i32),
+    canvas_width: i32,
+    canvas_height: i32,
+    frame: &mut [u8],
+    paint: &P,
+) {
+    let (outer_min_x, outer_min_y, outer_max_x, outer_max_y) = outer;
+    let (inner_min_x, inner_min_y, inner_max_x, inner_max_y) = inner;
+
+    if (inner_min_x > inner_max_x) || (inner_min_y > inner_max_y) {
+        fill_box(
+            outer_min_x,
+            outer_min_y,
+            outer_max_x,
+            outer_max_y,
+            canvas_width,
+            canvas_height,
+            frame,
+            paint,
+        );
+        return;
+    }
+
+    let bands = [
+        (
+            outer_min_x,
+            outer_min_y,
+            outer_max_x,
+            inner_min_y.saturating_sub(1),
+        ),
+        (
+            outer_min_x,
+            inner_max_y.saturating_add(1),
+            outer_max_x,
+            outer_max_y,
+        ),
+        (
+            outer_min_x,
+            inner_min_y,
+            inner_min_x.saturating_sub(1),
+            inner_max_y,
+        ),
+        (
+            inner_max_x.saturating_add(1),
+            inner_min_y,
+            outer_max_x,
+            inner_max_y,
+        ),
+    ];
+    for (min_x, min_y, max_x, max_y) in bands {
+        fill_box(
+            min_x,
+            min_y,
+            max_x,
+            max_y,
+            canvas_width,
+            canvas_height,
+            frame,
+            paint,
+        );
+    }
+}
+
+/// Paints a one pixel wide ring just inside the edges of a rotated rectangle,
+/// so the outline covers the border of the same pixels [`rect_rotated_filled`] does.
+#[inline]
+#[allow(clippy::too_many_arguments)]
+fn rotated_rect_outline(
+    frame: &mut [u8],
+    canvas_width: i32,
+    center_x: f64,
+    center_y: f64,
+    width: f64,
+    height: f64,
+    angle: f64,
+    rgba: &[u8; 4],
+) {
+    let canvas_height = frame.len() as i32 / 4 / canvas_width;
+    let outer = math::rotated_rect_corners(center_x, center_y, width, height, angle);
+    let mut contours = vec![outer];
+    // Rectangles two pixels across or less have no inside left, so they are drawn filled.
+    if width.abs() > 2.0 && height.abs() > 2.0 {
+        let shrink = |size: f64| size - 2.0 * size.signum();
+        contours.push(math::rotated_rect_corners(
+            center_x,
+            center_y,
+            shrink(width),
+            shrink(height),
+            angle,
+        ));
+    }
+
+    scanline::fill_contours(
+        frame,
+        canvas_width,
+        canvas_height,
+        &contours,
+        FillRule::EvenOdd,
+        rgba,
+    );
+}
+
+/// Draws a line between every pair of neighbouring points, without closing the shape.
+fn polyline(frame: &mut [u8], canvas_width: i32, points: &[(f64, f64)], rgba: &[u8; 4]) {
+    for segment in points.windows(2) {
+        let ((x0, y0), (x1, y1)) = (segment[0], segment[1]);
+        line(frame, canvas_width, x0, y0, x1, y1, rgba);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const WHITE: [u8; 4] = [255, 255, 255, 255];
+
+    /// Which pixels of a frame have been drawn to.
+    fn drawn(frame: &[u8]) -> Vec<bool> {
+        frame.chunks_exact(4).map(|pixel| pixel[3] != 0).collect()
+    }
+
+    /// A 4x3 frame whose pixels have their index in the red channel.
+    fn numbered_frame() -> Vec<u8> {
+        (0..12u8).flat_map(|i| [i, 0, 0, 255]).collect()
+    }
+
+    #[test]
+    fn get_pixel_reads_pixels_inside_of_the_frame() {
+        let frame = numbered_frame();
+        assert_eq!(get_pixel(&frame, 4, 0, 0), Some([0, 0, 0, 255]));
+        assert_eq!(get_pixel(&frame, 4, 3, 2), Some([11, 0, 0, 255]));
+        assert_eq!(get_pixel(&frame, 4, 1, 1), Some([5, 0, 0, 255]));
+    }
+
+    #[test]
+    fn get_pixel_is_none_outside_of_the_frame() {
+        let frame = numbered_frame();
+        for (x, y) in [(-1, 0), (0, -1), (4, 0), (0, 3), (i32::MIN, i32::MAX)] {
+            assert_eq!(get_pixel(&frame, 4, x, y), None, "({}, {})", x, y);
+        }
+        assert_eq!(get_pixel(&frame, 0, 0, 0), None);
+        assert_eq!(get_pixel(&frame, -4, 0, 0), None);
+        assert_eq!(get_pixel(&[], 4, 0, 0), None);
+    }
+
+    #[test]
+    fn sample_clamps_to_the_edges() {
+        let frame = numbered_frame();
+        assert_eq!(sample(&frame, 4, 2, 1), Some([6, 0, 0, 255]));
+        assert_eq!(sample(&frame, 4, -5, -5), Some([0, 0, 0, 255]));
+        assert_eq!(sample(&frame, 4, 100, -5), Some([3, 0, 0, 255]));
+        assert_eq!(sample(&frame, 4, -5, 100), Some([8, 0, 0, 255]));
+        assert_eq!(sample(&frame, 4, 100, 1), Some([7, 0, 0, 255]));
+        assert_eq!(sample(&frame, 4, i32::MAX, i32::MAX), Some([11, 0, 0, 255]));
+    }
+
+    #[test]
+    fn sample_is_none_without_pixels() {
+        assert_eq!(sample(&numbered_frame(), 0, 0, 0), None);
+        assert_eq!(sample(&numbered_frame(), -4, 0, 0), None);
+        assert_eq!(sample(&[], 4, 0, 0), None);
+    }
+
+    #[test]
+    fn copy_region_copies_the_pixels_inside_of_the_frame() {
+        let copy = copy_region(&numbered_frame(), 4, Region::new(1, 1, 2, 2));
+        assert_eq!((copy.width(), copy.height()), (2, 2));
+        let red: Vec<u8> = copy
+            .pixels()
+            .chunks_exact(4)
+            .map(|pixel| pixel[0])
+            .collect();
+        assert_eq!(red, [5, 6, 9, 10]);
+    }
+
+    #[test]
+    fn copy_region_leaves_parts_outside_of_the_frame_transparent() {
+        // Hangs off the left and the bottom of the frame.
+        let copy = copy_region(&numbered_frame(), 4, Region::new(-1, 1, 3, 3));
+        assert_eq!((copy.width(), copy.height()), (3, 3));
+        let pixels: Vec<Option<u8>> = copy
+            .pixels()
+            .chunks_exact(4)
+            .map(|pixel| (pixel[3] != 0).then_some(pixel[0]))
+            .collect();
+        assert_eq!(
+            pixels,
+            [
+                None,
+                Some(4),
+                Some(5),
+                None,
+                Some(8),
+                Some(9),
+                None,
+                None,
+                None
+            ]
+        );
+
+        let outside = copy_region(&numbered_frame(), 4, Region::new(10, 10, 2, 2));
+        assert_eq!(outside.pixels(), [0; 16]);
+        let no_frame = copy_region(&numbered_frame(), 0, Region::new(0, 0, 2, 2));
+        assert_eq!(no_frame.pixels(), [0; 16]);
+    }
+
+    #[test]
+    fn rotated_rect_outline_is_the_border_of_the_fill() {
+        const SIZE: i32 = 40;
+        for angle in [0.0, 0.3, std::f64::consts::FRAC_PI_4, 1.0, 2.5] {
+            let mut outline = vec![0; (SIZE * SIZE * 4) as usize];
+            let mut filled = outline.clone();
+            rect_rotated(&mut outline, SIZE, 20.5, 19.5, 21.0, 11.0, angle, &WHITE);
+            rect_rotated_filled(&mut filled, SIZE, 20.5, 19.5, 21.0, 11.0, angle, &WHITE);
+            let (outline, filled) = (drawn(&outline), drawn(&filled));
+
+            for y in 0..SIZE {
+                for x in 0..SIZE {
+                    let index = (y * SIZE + x) as usize;
+                    let inside = |x: i32, y: i32| filled[(y * SIZE + x) as usize];
+                    let on_border = inside(x, y)
+                        && !(inside(x - 1, y)
+                            && inside(x + 1, y)
+                            && inside(x, y - 1)
+                            && inside(x, y + 1));
+                    assert!(
+                        !outline[index] || filled[index],
+                        "outline outside of the fill at ({}, {}) for angle {}",
+                        x,
+                        y,
+                        angle
+                    );
+                    if on_border {
+                        assert!(
+                            outline[index],
+                            "border pixel ({}, {}) missing for angle {}",
+                            x, y, angle
+                        );
+                    }
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn rotated_rect_outline_matches_the_fill_without_rotation() {
+        let mut outline = vec![0; 12 * 12 * 4];
+        rect_rotated(&mut outline, 12, 5.5, 5.5, 6.0, 4.0, 0.0, &WHITE);
+        let outline = drawn(&outline);
+
+        // The fill covers x 3..=8 and y 4..=7.
+        for y in 0..12 {
+            for x in 0..12 {
+                let border = (3..=8).contains(&x)
+                    && (4..=7).contains(&y)
+                    && (x == 3 || x == 8 || y == 4 || y == 7);
+                assert_eq!(outline[y * 12 + x], border, "pixel ({}, {})", x, y);
+            }
+        }
+    }
+
+    #[test]
+    fn inside_triangle_stroke_is_the_border_of_the_fill() {
+        let triangles = [
+            [(2, 2), (17, 4), (6, 17)],
+            [(10, 1), (18, 18), (1, 12)],
+            [(3, 3), (16, 3), (3, 16)],
+            [(1, 18), (9, 2), (18, 17)],
+        ];
+        for [(v0x, v0y), (v1x, v1y), (v2x, v2y)] in triangles {
+            let mut fill = vec![0; 20 * 20 * 4];
+            triangle_filled(&mut fill, 20, v0x, v0y, v1x, v1y, v2x, v2y, &WHITE);
+            let fill = drawn(&fill);
+
+            let mut stroke = vec![0; 20 * 20 * 4];
+            triangle_stroke(
+                &mut stroke,
+                20,
+                v0x,
+                v0y,
+                v1x,
+                v1y,
+                v2x,
+                v2y,
+                1.0,
+                StrokeAlignment::Inside,
+                &WHITE,
+            );
+            let stroke = drawn(&stroke);
+
+            for (i, (&stroked, &filled)) in stroke.iter().zip(&fill).enumerate() {
+                assert!(!stroked || filled, "pixel ({}, {})", i % 20, i / 20);
+            }
+
+            // The first and the last pixel the fill covers on every row are part of the stroke.
+            for (y, row) in fill.chunks_exact(20).enumerate() {
+                let first = row.iter().position(|&filled| filled);
+                let last = row.iter().rposition(|&filled| filled);
+                for x in first.into_iter().chain(last) {
+                    assert!(stroke[y * 20 + x], "pixel ({}, {})", x, y);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn huge_and_invalid_stroke_widths_are_clamped() {
+        let mut filled = vec![0; 8 * 8 * 4];
+        rect_filled(&mut filled, 8, 2, 2, 5, 6, &WHITE);
+
+        let mut frame = vec![0; 8 * 8 * 4];
+        rect_stroke(
+            &mut frame,
+            8,
+            2,
+            2,
+            5,
+            6,
+            1e12,
+            StrokeAlignment::Inside,
+            &WHITE,
+        );
+        assert_eq!(frame, filled);
+
+        let mut frame = vec![0; 8 * 8 * 4];
+        rect_stroke(
+            &mut frame,
+            8,
+            2,
+            2,
+            5,
+            6,
+            1e12,
+            StrokeAlignment::Center,
+            &WHITE,
+        );
+        assert!(drawn(&frame).iter().all(|&drawn| drawn));
+
+        let mut frame = vec![0; 8 * 8 * 4];
+        square_stroke(
+            &mut frame,
+            8,
+            4.0,
+            4.0,
+            4.0,
+            1e12,
+            StrokeAlignment::Outside,
+            &WHITE,
+        );
+        for (i, &drawn) in drawn(&frame).iter().enumerate() {
+            let inside = (2..=6).contains(&(i % 8)) && (2..=6).contains(&(i / 8));
+            assert_eq!(drawn, !inside, "pixel ({}, {})", i % 8, i / 8);
+        }
+
+        for outline_width in [-3.0, f64::NAN, f64::NEG_INFINITY] {
+            let mut frame = vec![0; 8 * 8 * 4];
+            rect_stroke(
+                &mut frame,
+                8,
+                2,
+                2,
+                5,
+                6,
+                outline_width,
+                StrokeAlignment::Center,
+                &WHITE,
+            );
+            square_stroke(
+                &mut frame,
+                8,
+                4.0,
+                4.0,
+                4.0,
+                outline_width,
+                StrokeAlignment::Center,
+                &WHITE,
+            );
+            assert!(
+                drawn(&frame).iter().all(|&drawn| !drawn),
+                "{}",
+                outline_width
+            );
+        }
+
+        // The frame lies far inside of both edges of this outline, so nothing is drawn, but nothing overflows either.
+        let mut frame = vec![0; 8 * 8 * 4];
+        rect_stroke(
+            &mut frame,
+            8,
+            i32::MIN,
+            i32::MIN,
+            i32::MAX,
+            i32::MAX,
+            f64::INFINITY,
+            StrokeAlignment::Center,
+            &WHITE,
+        );
+        assert!(drawn(&frame).iter().all(|&drawn| !drawn));
+    }
 }
@@ -1,31 +1,182 @@
-use std::mem;
+use std::f64::consts::{FRAC_PI_2, PI, TAU};
 
 pub fn distance(origin_x: f64, origin_y: f64, x: f64, y: f64) -> f64 {
     let x_dist = (origin_x - x).abs();
     let y_dist = (origin_y - y).abs();
-    let dist = (x_dist.powf(2.0) + y_dist.powf(2.0)).sqrt();
-    dist
-}
-
-/// Sorts in ascending order.
-pub fn simple_bubble_sort_vector_by_y(
-    mv0x: &mut i32,
-    mv0y: &mut i32,
-    mv1x: &mut i32,
-    mv1y: &mut i32,
-    mv2x: &mut i32,
-    mv2y: &mut i32,
-) {
-    if mv0y > mv1y {
-        mem::swap(mv0x, mv1x);
-        mem::swap(mv0y, mv1y);
+    (x_dist.powf(2.0) + y_dist.powf(2.0)).sqrt()
+}
+
+/// Moves every edge of a convex polygon outwards by `offset` (or inwards, if `offset` is negative),
+/// and returns the corners where the moved edges meet.
+pub fn offset_convex_polygon(points: &[(f64, f64)], offset: f64) -> Vec<(f64, f64)> {
+    let count = points.len() as f64;
+    let centroid_x = points.iter().map(|p| p.0).sum::<f64>() / count;
+    let centroid_y = points.iter().map(|p| p.1).sum::<f64>() / count;
+
+    // Each edge is stored as a point on the moved edge plus its direction.
+    let edges: Vec<((f64, f64), (f64, f64))> = points
+        .iter()
+        .enumerate()
+        .map(|(i, &(x0, y0))| {
+            let (x1, y1) = points[(i + 1) % points.len()];
+            let length = distance(x0, y0, x1, y1).max(f64::EPSILON);
+            let (dx, dy) = ((x1 - x0) / length, (y1 - y0) / length);
+
+            // Flip the normal if it points towards the middle of the polygon.
+            let (mut nx, mut ny) = (dy, -dx);
+            if nx * (centroid_x - x0) + ny * (centroid_y - y0) > 0.0 {
+                nx = -nx;
+                ny = -ny;
+            }
+
+            ((x0 + nx * offset, y0 + ny * offset), (dx, dy))
+        })
+        .collect();
+
+    (0..edges.len())
+        .map(|i| {
+            let ((px, py), (pdx, pdy)) = edges[(i + edges.len() - 1) % edges.len()];
+            let ((qx, qy), (qdx, qdy)) = edges[i];
+            let denominator = pdx * qdy - pdy * qdx;
+            if denominator.abs() < f64::EPSILON {
+                return (qx, qy);
+            }
+            let t = ((qx - px) * qdy - (qy - py) * qdx) / denominator;
+            (px + pdx * t, py + pdy * t)
+        })
+        .collect()
+}
+
+/// The radius of the largest circle that fits inside of a triangle.
+pub fn triangle_inradius(points: &[(f64, f64); 3]) -> f64 {
+    let [(x0, y0), (x1, y1), (x2, y2)] = *points;
+    let doubled_area = ((x1 - x0) * (y2 - y0) - (x2 - x0) * (y1 - y0)).abs();
+    let perimeter = distance(x0, y0, x1, y1) + distance(x1, y1, x2, y2) + distance(x2, y2, x0, y0);
+    if perimeter == 0.0 {
+        return 0.0;
+    }
+    doubled_area / perimeter
+}
+
+/// The corners of a rectangle with the given center and size, rotated by `angle` radians around its center.
+pub fn rotated_rect_corners(
+    center_x: f64,
+    center_y: f64,
+    width: f64,
+    height: f64,
+    angle: f64,
+) -> [(f64, f64); 4] {
+    let (sin, cos) = angle.sin_cos();
+    let (half_width, half_height) = (width / 2.0, height / 2.0);
+
+    [
+        (-half_width, -half_height),
+        (half_width, -half_height),
+        (half_width, half_height),
+        (-half_width, half_height),
+    ]
+    .map(|(x, y)| (center_x + x * cos - y * sin, center_y + x * sin + y * cos))
+}
+
+/// The shortest distance from a point to the line segment between `start` and `end`.
+pub fn distance_to_segment(point: (f64, f64), start: (f64, f64), end: (f64, f64)) -> f64 {
+    let (dx, dy) = (end.0 - start.0, end.1 - start.1);
+    let length_squared = dx * dx + dy * dy;
+    if length_squared == 0.0 {
+        return distance(start.0, start.1, point.0, point.1);
     }
-    if mv0y > mv2y {
-        mem::swap(mv0x, mv2x);
-        mem::swap(mv0y, mv2y);
+
+    let t =
+        (((point.0 - start.0) * dx + (point.1 - start.1) * dy) / length_squared).clamp(0.0, 1.0);
+    distance(start.0 + dx * t, start.1 + dy * t, point.0, point.1)
+}
+
+/// Regular polygons and stars never get more corners than this. Well before then they look like circles,
+/// and it keeps huge counts from overflowing or allocating gigabytes of corners.
+pub const MAXIMUM_CORNERS: u32 = 4096;
+
+/// The corners of a regular polygon. With a rotation of 0 the first corner points straight up.
+/// At most [`MAXIMUM_CORNERS`] sides are used.
+pub fn regular_polygon_points(
+    center_x: f64,
+    center_y: f64,
+    radius: f64,
+    sides: u32,
+    rotation: f64,
+) -> Vec<(f64, f64)> {
+    let sides = sides.min(MAXIMUM_CORNERS);
+    (0..sides)
+        .map(|i| {
+            let angle = rotation - FRAC_PI_2 + TAU * i as f64 / sides as f64;
+            (
+                center_x + radius * angle.cos(),
+                center_y + radius * angle.sin(),
+            )
+        })
+        .collect()
+}
+
+/// The corners of a star, alternating between the outer and the inner radius.
+/// With a rotation of 0 the first point of the star points straight up. The point count is capped so the star has at most
+/// [`MAXIMUM_CORNERS`] corners.
+pub fn star_points(
+    center_x: f64,
+    center_y: f64,
+    outer_radius: f64,
+    inner_radius: f64,
+    points: u32,
+    rotation: f64,
+) -> Vec<(f64, f64)> {
+    let points = points.min(MAXIMUM_CORNERS / 2);
+    (0..points * 2)
+        .map(|i| {
+            let radius = if i % 2 == 0 {
+                outer_radius
+            } else {
+                inner_radius
+            };
+            let angle = rotation - FRAC_PI_2 + PI * i as f64 / points as f64;
+            (
+                center_x + radius * angle.cos(),
+                center_y + radius * angle.sin(),
+            )
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn huge_corner_counts_are_capped() {
+        assert_eq!(
+            regular_polygon_points(0.0, 0.0, 10.0, u32::MAX, 0.0).len(),
+            MAXIMUM_CORNERS as usize
+        );
+        assert_eq!(
+            star_points(0.0, 0.0, 10.0, 5.0, u32::MAX, 0.0).len(),
+            MAXIMUM_CORNERS as usize
+        );
+        assert_eq!(
+            star_points(0.0, 0.0, 10.0, 5.0, u32::MAX / 2 + 1, 0.0).len(),
+            MAXIMUM_CORNERS as usize
+        );
     }
-    if mv1y > mv2y {
-        mem::swap(mv1x, mv2x);
-        mem::swap(mv1y, mv2y);
+
+    #[test]
+    fn star_points_alternate_between_the_radii() {
+        let corners = star_points(0.0, 0.0, 10.0, 5.0, 5, 0.0);
+        assert_eq!(corners.len(), 10);
+        for (i, &(x, y)) in corners.iter().enumerate() {
+            let expected = if i % 2 == 0 { 10.0 } else { 5.0 };
+            assert!(
+                (distance(0.0, 0.0, x, y) - expected).abs() < 1e-9,
+                "corner {}",
+                i
+            );
+        }
+        // The first point is straight up.
+        assert!(corners[0].0.abs() < 1e-9 && (corners[0].1 + 10.0).abs() < 1e-9);
     }
 }
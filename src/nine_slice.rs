@@ -0,0 +1,287 @@
+use crate::{blit, BlitOptions, Filter, Image, Region, Rotation};
+
+/// How the edges or the center of a [`NineSlice`] fill the space between the corners.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SliceMode {
+    /// The slice is scaled to fill the space.
+    Stretch,
+    /// The slice is repeated at its original size, and the last repeat is cut off.
+    Tile,
+}
+
+/// How an image is cut into nine slices, so it can be drawn at any size without distorting its border.
+///
+/// The insets are measured in pixels from the edges of the image. The four corners are always drawn at their
+/// original size, the top and bottom edges only grow horizontally, the left and right edges only grow vertically,
+/// and the center fills whatever is left.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct NineSlice {
+    /// The width of the left column of slices.
+    pub left: u32,
+    /// The height of the top row of slices.
+    pub top: u32,
+    /// The width of the right column of slices.
+    pub right: u32,
+    /// The height of the bottom row of slices.
+    pub bottom: u32,
+    /// How the four edges fill the space between the corners.
+    pub edges: SliceMode,
+    /// How the center fills the space between the edges.
+    pub center: SliceMode,
+    /// How stretched slices are sampled.
+    pub filter: Filter,
+}
+
+impl NineSlice {
+    /// Cuts the image at the given insets, stretching the edges and the center.
+    pub fn new(left: u32, top: u32, right: u32, bottom: u32) -> Self {
+        Self {
+            left,
+            top,
+            right,
+            bottom,
+            edges: SliceMode::Stretch,
+            center: SliceMode::Stretch,
+            filter: Filter::Nearest,
+        }
+    }
+
+    /// Cuts the image the same distance from every edge, stretching the edges and the center.
+    pub fn uniform(inset: u32) -> Self {
+        Self::new(inset, inset, inset, inset)
+    }
+}
+
+/// Splits `length` into the sizes of a start slice, a middle slice and an end slice.
+///
+/// The start and end are cut short if they don't fit.
+fn split(length: u32, start: u32, end: u32) -> [u32; 3] {
+    let start = start.min(length);
+    let end = end.min(length - start);
+    [start, length - start - end, end]
+}
+
+/// Splits the destination like [`split`], except the start and end shrink by the same factor when they don't fit,
+/// leaving the middle empty.
+fn split_destination(length: u32, start: u32, end: u32) -> [u32; 3] {
+    let border = start as u64 + end as u64;
+    if border <= length as u64 {
+        return [start, length - start - end, end];
+    }
+    let start = (start as u64 * length as u64 / border) as u32;
+    [start, 0, length - start]
+}
+
+/// Draws the source region of the image into the destination, keeping the corners of the nine slice unscaled.
+pub fn nine_slice(
+    frame: &mut [u8],
+    canvas_width: i32,
+    canvas_height: i32,
+    image: &Image,
+    slice: &NineSlice,
+    destination: Region,
+    options: &BlitOptions,
+) {
+    let Some(source) = options.source_region(image) else {
+        return;
+    };
+
+    let source_columns = split(source.width, slice.left, slice.right);
+    let source_rows = split(source.height, slice.top, slice.bottom);
+    let destination_columns =
+        split_destination(destination.width, source_columns[0], source_columns[2]);
+    let destination_rows = split_destination(destination.height, source_rows[0], source_rows[2]);
+
+    // The cells are laid out in i64, since the last ones can start past i32::MAX. Those are never on the canvas.
+    let mut source_y = source.y;
+    let mut destination_y = destination.y as i64;
+    for row in 0..3 {
+        let mut source_x = source.x;
+        let mut destination_x = destination.x as i64;
+        for column in 0..3 {
+            let cell_source =
+                Region::new(source_x, source_y, source_columns[column], source_rows[row]);
+            let cell_destination = Region::new(
+                destination_x.min(i32::MAX as i64) as i32,
+                destination_y.min(i32::MAX as i64) as i32,
+                destination_columns[column],
+                destination_rows[row],
+            );
+            let mode = match (column, row) {
+                (1, 1) => slice.center,
+                (1, _) | (_, 1) => slice.edges,
+                _ => SliceMode::Stretch,
+            };
+            // Edges only repeat along the side they run along.
+            let (tile_x, tile_y) = match mode {
+                SliceMode::Stretch => (false, false),
+                SliceMode::Tile => (column == 1, row == 1),
+            };
+
+            draw_cell(
+                frame,
+                canvas_width,
+                canvas_height,
+                image,
+                cell_source,
+                cell_destination,
+                (tile_x, tile_y),
+                slice.filter,
+                options,
+            );
+
+            source_x += source_columns[column] as i32;
+            destination_x += destination_columns[column] as i64;
+        }
+        source_y += source_rows[row] as i32;
+        destination_y += destination_rows[row] as i64;
+    }
+}
+
+/// Fills the destination with the source, repeating it at its original size along the tiled axes
+/// and stretching it along the others.
+#[allow(clippy::too_many_arguments)]
+fn draw_cell(
+    frame: &mut [u8],
+    canvas_width: i32,
+    canvas_height: i32,
+    image: &Image,
+    source: Region,
+    destination: Region,
+    (tile_x, tile_y): (bool, bool),
+    filter: Filter,
+    options: &BlitOptions,
+) {
+    if source.width == 0 || source.height == 0 || destination.width == 0 || destination.height == 0
+    {
+        return;
+    }
+
+    let tile_width = if tile_x {
+        source.width
+    } else {
+        destination.width
+    };
+    let tile_height = if tile_y {
+        source.height
+    } else {
+        destination.height
+    };
+    let right = destination.x as i64 + destination.width as i64;
+    let bottom = destination.y as i64 + destination.height as i64;
+    let Some(first_x) = first_visible_tile(destination.x, right, tile_width, canvas_width) else {
+        return;
+    };
+    let Some(first_y) = first_visible_tile(destination.y, bottom, tile_height, canvas_height)
+    else {
+        return;
+    };
+
+    let mut y = first_y;
+    while y < bottom.min(canvas_height as i64) {
+        let height = (tile_height as i64).min(bottom - y) as u32;
+        let mut x = first_x;
+        while x < right.min(canvas_width as i64) {
+            let width = (tile_width as i64).min(right - x) as u32;
+            // A tile that is cut off at the end only uses the start of the source.
+            let tile_source = Region::new(
+                source.x,
+                source.y,
+                if tile_x { width } else { source.width },
+                if tile_y { height } else { source.height },
+            );
+            let tile_options = BlitOptions {
+                source: Some(tile_source),
+                flip_x: false,
+                flip_y: false,
+                rotation: Rotation::Rotate0,
+                ..*options
+            };
+
+            // Every tile starts before the right or bottom edge of the canvas, so it fits in an i32.
+            if tile_source.width == width && tile_source.height == height {
+                blit::blit(
+                    frame,
+                    canvas_width,
+                    canvas_height,
+                    image,
+                    x as i32,
+                    y as i32,
+                    &tile_options,
+                );
+            } else {
+                blit::blit_scaled(
+                    frame,
+                    canvas_width,
+                    canvas_height,
+                    image,
+                    Region::new(x as i32, y as i32, width, height),
+                    filter,
+                    &tile_options,
+                );
+            }
+            x += width as i64;
+        }
+        y += height as i64;
+    }
+}
+
+/// Finds where the first tile that reaches onto the canvas starts along one axis, skipping the tiles before it.
+///
+/// Returns `None` if the destination from `start` up to `end` is entirely off the canvas.
+fn first_visible_tile(start: i32, end: i64, tile_length: u32, canvas_length: i32) -> Option<i64> {
+    if end <= 0 || start >= canvas_length {
+        return None;
+    }
+    let (start, tile_length) = (start as i64, tile_length as i64);
+    Some(start + (-start).max(0) / tile_length * tile_length)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Tiles a single row image with the given red values across a single row frame, as the center of a nine slice.
+    fn tile_row(values: &[u8], destination: Region, width: i32) -> Vec<u8> {
+        let pixels = values
+            .iter()
+            .flat_map(|&value| [value, 0, 0, 255])
+            .collect();
+        let image = Image::from_rgba(values.len() as u32, 1, pixels);
+        let slice = NineSlice {
+            center: SliceMode::Tile,
+            ..NineSlice::uniform(0)
+        };
+
+        let mut frame = vec![0; width as usize * 4];
+        nine_slice(
+            &mut frame,
+            width,
+            1,
+            &image,
+            &slice,
+            destination,
+            &BlitOptions::default(),
+        );
+        frame.chunks_exact(4).map(|pixel| pixel[0]).collect()
+    }
+
+    #[test]
+    fn tiles_keep_their_place_when_starting_off_canvas() {
+        assert_eq!(tile_row(&[1, 2], Region::new(0, 0, 4, 1), 4), [1, 2, 1, 2]);
+        assert_eq!(tile_row(&[1, 2], Region::new(-3, 0, 8, 1), 4), [2, 1, 2, 1]);
+        assert_eq!(
+            tile_row(&[1, 2, 3], Region::new(1, 0, 5, 1), 5),
+            [0, 1, 2, 3, 1]
+        );
+    }
+
+    #[test]
+    fn destinations_at_the_ends_of_the_range_do_not_overflow() {
+        let far_left = Region::new(i32::MIN, 0, u32::MAX, 1);
+        assert_eq!(tile_row(&[1, 2], far_left, 4), [1, 2, 1, 2]);
+
+        let far_right = Region::new(i32::MAX - 2, 0, u32::MAX, 1);
+        assert_eq!(tile_row(&[1, 2], far_right, 4), [0, 0, 0, 0]);
+    }
+}
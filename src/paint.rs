@@ -0,0 +1,750 @@
+use crate::Image;
+
+/// Something that decides the color of every pixel a filled shape covers.
+///
+/// A plain `[u8; 4]` RGBA color is a paint that colors every pixel the same, so every filled primitive still accepts
+/// `&[255, 255, 255, 255]` as before. Gradients and patterns implement this trait to vary the color across the frame.
+pub trait Paint {
+    /// The color of the pixel at (x, y), or `None` to leave that pixel untouched.
+    fn color_at(&self, x: i32, y: i32) -> Option<[u8; 4]>;
+
+    /// Paints a run of pixels in a single row, where the first pixel of `span` is at (x, y).
+    ///
+    /// The default implementation calls [`Paint::color_at`] for every pixel. Paints that can fill whole runs
+    /// faster, or that need to look at the pixels already in the frame, can override it.
+    fn fill_span(&self, span: &mut [u8], x: i32, y: i32) {
+        for (i, pixel) in span.chunks_exact_mut(4).enumerate() {
+            if let Some(rgba) = self.color_at(x + i as i32, y) {
+                pixel.copy_from_slice(&rgba);
+            }
+        }
+    }
+}
+
+impl Paint for [u8; 4] {
+    fn color_at(&self, _x: i32, _y: i32) -> Option<[u8; 4]> {
+        Some(*self)
+    }
+
+    fn fill_span(&self, span: &mut [u8], _x: i32, _y: i32) {
+        for pixel in span.chunks_exact_mut(4) {
+            pixel.copy_from_slice(self);
+        }
+    }
+}
+
+/// A color at a position along a gradient, where 0.0 is the start of the gradient and 1.0 is the end.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ColorStop {
+    /// Where the color is placed along the gradient.
+    pub offset: f64,
+    /// The color at this position.
+    pub rgba: [u8; 4],
+}
+
+impl ColorStop {
+    /// Creates a color stop at the given offset.
+    pub fn new(offset: f64, rgba: [u8; 4]) -> Self {
+        Self { offset, rgba }
+    }
+}
+
+/// What a gradient does past its start and end.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SpreadMode {
+    /// The colors at the ends continue forever.
+    Pad,
+    /// The gradient starts over from the beginning.
+    Repeat,
+    /// The gradient runs backwards, then forwards again, and so on.
+    Reflect,
+}
+
+/// The color stops and spread mode that every kind of gradient shares.
+#[derive(Debug, Clone, PartialEq)]
+struct ColorRamp {
+    stops: Vec<ColorStop>,
+    spread: SpreadMode,
+}
+
+impl ColorRamp {
+    fn new(mut stops: Vec<ColorStop>, spread: SpreadMode) -> Self {
+        stops.sort_by(|a, b| a.offset.total_cmp(&b.offset));
+        Self { stops, spread }
+    }
+
+    /// The color at a position along the gradient, after applying the spread mode.
+    fn color_at(&self, offset: f64) -> Option<[u8; 4]> {
+        let offset = match self.spread {
+            SpreadMode::Pad => offset.clamp(0.0, 1.0),
+            SpreadMode::Repeat => offset - offset.floor(),
+            SpreadMode::Reflect => {
+                let offset = offset.rem_euclid(2.0);
+                if offset > 1.0 {
+                    2.0 - offset
+                } else {
+                    offset
+                }
+            }
+        };
+
+        let first = self.stops.first()?;
+        let last = self.stops.last()?;
+        if offset <= first.offset {
+            return Some(first.rgba);
+        }
+        if offset >= last.offset {
+            return Some(last.rgba);
+        }
+
+        let next = self.stops.iter().position(|stop| stop.offset > offset)?;
+        let (before, after) = (self.stops[next - 1], self.stops[next]);
+        let t = (offset - before.offset) / (after.offset - before.offset);
+        let mut rgba = [0; 4];
+        for (channel, (&from, &to)) in rgba.iter_mut().zip(before.rgba.iter().zip(&after.rgba)) {
+            *channel = (from as f64 + (to as f64 - from as f64) * t).round() as u8;
+        }
+        Some(rgba)
+    }
+}
+
+/// A paint that blends between colors along a straight line.
+///
+/// Every pixel on a line perpendicular to the gradient gets the same color.
+///
+/// # Example
+///
+/// ```no_run
+/// use pixels::{Pixels, SurfaceTexture};
+/// use pixels_primitives::{ColorStop, LinearGradient, SpreadMode};
+/// use winit::dpi::LogicalSize;
+/// use winit::event_loop::{EventLoop};
+/// use std::error::Error;
+/// use winit::window::WindowBuilder;
+///
+/// const WIDTH: i32 = 800;
+/// const HEIGHT: i32 = 800;
+///
+/// fn main() -> Result<(), Box<dyn Error>> {
+///     let event_loop = EventLoop::new();
+///     let window = {
+///     let size = LogicalSize::new(WIDTH as f64, HEIGHT as f64);
+///     WindowBuilder::new()
+///         .with_title("Linear Gradient Example")
+///         .with_inner_size(size)
+///         .with_min_inner_size(size)
+///         .build(&event_loop)
+///         .unwrap()
+///     };
+///
+///     let mut pixels = {
+///         let window_size = window.inner_size();
+///         let surface_texture = SurfaceTexture::new(window_size.width, window_size.height, &window);
+///         Pixels::new(WIDTH as u32, HEIGHT as u32, surface_texture)?
+///     };
+///
+///     let gradient = LinearGradient::new(
+///         (200.0, 200.0),
+///         (500.0, 300.0),
+///         vec![
+///             ColorStop::new(0.0, [255, 0, 0, 255]),
+///             ColorStop::new(1.0, [0, 0, 255, 255]),
+///         ],
+///         SpreadMode::Pad,
+///     );
+///
+///     pixels_primitives::rect_filled(pixels.get_frame(), WIDTH, 200, 200, 500, 300, &gradient);
+///
+///     // Run your event loop here!
+///
+///     Ok(())
+/// }
+///
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct LinearGradient {
+    start: (f64, f64),
+    end: (f64, f64),
+    ramp: ColorRamp,
+}
+
+impl LinearGradient {
+    /// Creates a gradient that runs from the first color stop at `start` to the last color stop at `end`.
+    ///
+    /// The stops do not have to be sorted. A gradient without any stops leaves every pixel untouched.
+    pub fn new(
+        start: (f64, f64),
+        end: (f64, f64),
+        stops: Vec<ColorStop>,
+        spread: SpreadMode,
+    ) -> Self {
+        Self {
+            start,
+            end,
+            ramp: ColorRamp::new(stops, spread),
+        }
+    }
+}
+
+impl Paint for LinearGradient {
+    fn color_at(&self, x: i32, y: i32) -> Option<[u8; 4]> {
+        let (dx, dy) = (self.end.0 - self.start.0, self.end.1 - self.start.1);
+        let length_squared = dx * dx + dy * dy;
+        if length_squared == 0.0 {
+            return self.ramp.color_at(0.0);
+        }
+
+        // Project the pixel onto the line between the start and end points.
+        let offset =
+            ((x as f64 - self.start.0) * dx + (y as f64 - self.start.1) * dy) / length_squared;
+        self.ramp.color_at(offset)
+    }
+}
+
+/// A paint that blends between colors in circles around a point.
+///
+/// The first color stop is at the focal point and the last one is on the circle with the given center and radius.
+/// Putting the focal point off center makes the gradient look like light falling on a sphere from the side.
+///
+/// # Example
+///
+/// ```no_run
+/// use pixels::{Pixels, SurfaceTexture};
+/// use pixels_primitives::{ColorStop, RadialGradient, SpreadMode};
+/// use winit::dpi::LogicalSize;
+/// use winit::event_loop::{EventLoop};
+/// use std::error::Error;
+/// use winit::window::WindowBuilder;
+///
+/// const WIDTH: i32 = 800;
+/// const HEIGHT: i32 = 800;
+///
+/// fn main() -> Result<(), Box<dyn Error>> {
+///     let event_loop = EventLoop::new();
+///     let window = {
+///     let size = LogicalSize::new(WIDTH as f64, HEIGHT as f64);
+///     WindowBuilder::new()
+///         .with_title("Radial Gradient Example")
+///         .with_inner_size(size)
+///         .with_min_inner_size(size)
+///         .build(&event_loop)
+///         .unwrap()
+///     };
+///
+///     let mut pixels = {
+///         let window_size = window.inner_size();
+///         let surface_texture = SurfaceTexture::new(window_size.width, window_size.height, &window);
+///         Pixels::new(WIDTH as u32, HEIGHT as u32, surface_texture)?
+///     };
+///
+///     let halo = RadialGradient::new(
+///         (400.0, 400.0),
+///         200.0,
+///         (400.0, 400.0),
+///         vec![
+///             ColorStop::new(0.0, [255, 255, 200, 255]),
+///             ColorStop::new(1.0, [0, 0, 0, 255]),
+///         ],
+///         SpreadMode::Pad,
+///     );
+///
+///     pixels_primitives::circle_filled(pixels.get_frame(), WIDTH, 400.0, 400.0, 200.0, &halo);
+///
+///     // Run your event loop here!
+///
+///     Ok(())
+/// }
+///
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct RadialGradient {
+    center: (f64, f64),
+    radius: f64,
+    focal: (f64, f64),
+    ramp: ColorRamp,
+}
+
+impl RadialGradient {
+    /// Creates a gradient that runs from the first color stop at `focal` to the last color stop on the circle
+    /// around `center`. Use the center as the focal point for an ordinary, evenly spaced radial gradient.
+    ///
+    /// If the focal point is outside of the circle, pixels outside of the cone the gradient forms are left untouched.
+    pub fn new(
+        center: (f64, f64),
+        radius: f64,
+        focal: (f64, f64),
+        stops: Vec<ColorStop>,
+        spread: SpreadMode,
+    ) -> Self {
+        Self {
+            center,
+            radius,
+            focal,
+            ramp: ColorRamp::new(stops, spread),
+        }
+    }
+}
+
+impl Paint for RadialGradient {
+    fn color_at(&self, x: i32, y: i32) -> Option<[u8; 4]> {
+        // Find the circle the pixel lies on. Circles are interpolated between a point at the focus (offset 0)
+        // and the outer circle (offset 1), so solve |pixel - focal - offset * (center - focal)| = offset * radius.
+        let (dx, dy) = (x as f64 - self.focal.0, y as f64 - self.focal.1);
+        let (ex, ey) = (self.center.0 - self.focal.0, self.center.1 - self.focal.1);
+        let a = ex * ex + ey * ey - self.radius * self.radius;
+        let b = dx * ex + dy * ey;
+        let c = dx * dx + dy * dy;
+
+        let offset = if a.abs() < f64::EPSILON {
+            if b == 0.0 {
+                return self.ramp.color_at(0.0);
+            }
+            c / (2.0 * b)
+        } else {
+            let discriminant = b * b - a * c;
+            if discriminant < 0.0 {
+                return None;
+            }
+            let root = discriminant.sqrt();
+            ((b + root) / a).max((b - root) / a)
+        };
+
+        if offset < 0.0 || offset.is_nan() {
+            return None;
+        }
+        self.ramp.color_at(offset)
+    }
+}
+
+/// A paint that blends between colors around a point, like the hands of a clock sweeping around.
+///
+/// Offset 0 points in the direction of the start angle, and the offsets increase clockwise until they reach 1 after
+/// a full turn. Making the first and last color stops the same gives a seamless color wheel.
+///
+/// # Example
+///
+/// ```no_run
+/// use pixels::{Pixels, SurfaceTexture};
+/// use pixels_primitives::{ColorStop, ConicGradient};
+/// use winit::dpi::LogicalSize;
+/// use winit::event_loop::{EventLoop};
+/// use std::error::Error;
+/// use winit::window::WindowBuilder;
+///
+/// const WIDTH: i32 = 800;
+/// const HEIGHT: i32 = 800;
+///
+/// fn main() -> Result<(), Box<dyn Error>> {
+///     let event_loop = EventLoop::new();
+///     let window = {
+///     let size = LogicalSize::new(WIDTH as f64, HEIGHT as f64);
+///     WindowBuilder::new()
+///         .with_title("Conic Gradient Example")
+///         .with_inner_size(size)
+///         .with_min_inner_size(size)
+///         .build(&event_loop)
+///         .unwrap()
+///     };
+///
+///     let mut pixels = {
+///         let window_size = window.inner_size();
+///         let surface_texture = SurfaceTexture::new(window_size.width, window_size.height, &window);
+///         Pixels::new(WIDTH as u32, HEIGHT as u32, surface_texture)?
+///     };
+///
+///     let color_wheel = ConicGradient::new(
+///         (400.0, 400.0),
+///         0.0,
+///         vec![
+///             ColorStop::new(0.0, [255, 0, 0, 255]),
+///             ColorStop::new(1.0 / 3.0, [0, 255, 0, 255]),
+///             ColorStop::new(2.0 / 3.0, [0, 0, 255, 255]),
+///             ColorStop::new(1.0, [255, 0, 0, 255]),
+///         ],
+///     );
+///
+///     pixels_primitives::circle_filled(pixels.get_frame(), WIDTH, 400.0, 400.0, 200.0, &color_wheel);
+///
+///     // Run your event loop here!
+///
+///     Ok(())
+/// }
+///
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct ConicGradient {
+    center: (f64, f64),
+    start_angle: f64,
+    ramp: ColorRamp,
+}
+
+impl ConicGradient {
+    /// Creates a gradient that sweeps around `center`, starting at `start_angle` radians.
+    /// An angle of 0 points to the right.
+    pub fn new(center: (f64, f64), start_angle: f64, stops: Vec<ColorStop>) -> Self {
+        Self {
+            center,
+            start_angle,
+            ramp: ColorRamp::new(stops, SpreadMode::Pad),
+        }
+    }
+}
+
+impl Paint for ConicGradient {
+    fn color_at(&self, x: i32, y: i32) -> Option<[u8; 4]> {
+        let angle = (y as f64 - self.center.1).atan2(x as f64 - self.center.0);
+        let offset = ((angle - self.start_angle) / (2.0 * std::f64::consts::PI)).rem_euclid(1.0);
+        self.ramp.color_at(offset)
+    }
+}
+
+/// Which directions an [`ImagePattern`] repeats its image in.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RepeatMode {
+    /// The image is tiled in both directions.
+    Repeat,
+    /// The image is tiled horizontally only.
+    RepeatX,
+    /// The image is tiled vertically only.
+    RepeatY,
+    /// The image is drawn once. Pixels outside of it are left untouched.
+    NoRepeat,
+}
+
+/// A paint that tiles an image across the shape being filled.
+///
+/// The image is sampled with the nearest pixel, so scaled up pixel art stays crisp.
+///
+/// # Example
+///
+/// ```no_run
+/// use pixels::{Pixels, SurfaceTexture};
+/// use pixels_primitives::{FillRule, Image, ImagePattern, RepeatMode};
+/// use winit::dpi::LogicalSize;
+/// use winit::event_loop::{EventLoop};
+/// use std::error::Error;
+/// use winit::window::WindowBuilder;
+///
+/// const WIDTH: i32 = 800;
+/// const HEIGHT: i32 = 800;
+///
+/// fn main() -> Result<(), Box<dyn Error>> {
+///     let event_loop = EventLoop::new();
+///     let window = {
+///     let size = LogicalSize::new(WIDTH as f64, HEIGHT as f64);
+///     WindowBuilder::new()
+///         .with_title("Image Pattern Example")
+///         .with_inner_size(size)
+///         .with_min_inner_size(size)
+///         .build(&event_loop)
+///         .unwrap()
+///     };
+///
+///     let mut pixels = {
+///         let window_size = window.inner_size();
+///         let surface_texture = SurfaceTexture::new(window_size.width, window_size.height, &window);
+///         Pixels::new(WIDTH as u32, HEIGHT as u32, surface_texture)?
+///     };
+///
+///     // A 2x2 checkerboard tile.
+///     let tile = Image::from_rgba(
+///         2,
+///         2,
+///         vec![
+///             0, 160, 0, 255, 0, 120, 0, 255,
+///             0, 120, 0, 255, 0, 160, 0, 255,
+///         ],
+///     );
+///     let grass = ImagePattern::new(&tile, (0.0, 0.0), 8.0, RepeatMode::Repeat);
+///
+///     pixels_primitives::polygon_filled(
+///         pixels.get_frame(),
+///         WIDTH,
+///         &[(100.0, 100.0), (600.0, 150.0), (500.0, 600.0), (150.0, 500.0)],
+///         FillRule::NonZero,
+///         &grass,
+///     );
+///
+///     // Run your event loop here!
+///
+///     Ok(())
+/// }
+///
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ImagePattern<'a> {
+    image: &'a Image,
+    offset: (f64, f64),
+    scale: f64,
+    repeat: RepeatMode,
+}
+
+impl<'a> ImagePattern<'a> {
+    /// Creates a pattern with the top left corner of one copy of the image at `offset`, where every pixel of the image
+    /// covers `scale` by `scale` pixels of the frame.
+    pub fn new(image: &'a Image, offset: (f64, f64), scale: f64, repeat: RepeatMode) -> Self {
+        Self {
+            image,
+            offset,
+            scale,
+            repeat,
+        }
+    }
+}
+
+impl Paint for ImagePattern<'_> {
+    fn color_at(&self, x: i32, y: i32) -> Option<[u8; 4]> {
+        let (width, height) = (self.image.width() as i64, self.image.height() as i64);
+        if width == 0 || height == 0 || self.scale <= 0.0 {
+            return None;
+        }
+
+        let mut image_x = ((x as f64 - self.offset.0) / self.scale).floor() as i64;
+        let mut image_y = ((y as f64 - self.offset.1) / self.scale).floor() as i64;
+        if let RepeatMode::Repeat | RepeatMode::RepeatX = self.repeat {
+            image_x = image_x.rem_euclid(width);
+        }
+        if let RepeatMode::Repeat | RepeatMode::RepeatY = self.repeat {
+            image_y = image_y.rem_euclid(height);
+        }
+
+        self.image.get_pixel(
+            image_x.clamp(-1, width) as i32,
+            image_y.clamp(-1, height) as i32,
+        )
+    }
+}
+
+/// The kinds of lines a [`Hatch`] is made out of.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum HatchStyle {
+    /// Evenly spaced horizontal lines.
+    Horizontal,
+    /// Evenly spaced vertical lines.
+    Vertical,
+    /// Diagonal lines running from the bottom left to the top right.
+    ForwardDiagonal,
+    /// Diagonal lines running from the top left to the bottom right.
+    BackwardDiagonal,
+    /// Horizontal and vertical lines crossing each other.
+    Cross,
+    /// Both kinds of diagonal lines crossing each other.
+    DiagonalCross,
+}
+
+/// A paint that fills shapes with evenly spaced lines, like the hatching of a technical drawing.
+///
+/// # Example
+///
+/// ```no_run
+/// use pixels::{Pixels, SurfaceTexture};
+/// use pixels_primitives::{Hatch, HatchStyle};
+/// use winit::dpi::LogicalSize;
+/// use winit::event_loop::{EventLoop};
+/// use std::error::Error;
+/// use winit::window::WindowBuilder;
+///
+/// const WIDTH: i32 = 800;
+/// const HEIGHT: i32 = 800;
+///
+/// fn main() -> Result<(), Box<dyn Error>> {
+///     let event_loop = EventLoop::new();
+///     let window = {
+///     let size = LogicalSize::new(WIDTH as f64, HEIGHT as f64);
+///     WindowBuilder::new()
+///         .with_title("Hatch Example")
+///         .with_inner_size(size)
+///         .with_min_inner_size(size)
+///         .build(&event_loop)
+///         .unwrap()
+///     };
+///
+///     let mut pixels = {
+///         let window_size = window.inner_size();
+///         let surface_texture = SurfaceTexture::new(window_size.width, window_size.height, &window);
+///         Pixels::new(WIDTH as u32, HEIGHT as u32, surface_texture)?
+///     };
+///
+///     let hatch = Hatch::new(
+///         HatchStyle::ForwardDiagonal,
+///         8.0,
+///         0.0,
+///         1.0,
+///         [0, 0, 0, 255],
+///         Some([255, 255, 255, 255]),
+///     );
+///
+///     pixels_primitives::rect_filled(pixels.get_frame(), WIDTH, 200, 200, 500, 300, &hatch);
+///
+///     // Run your event loop here!
+///
+///     Ok(())
+/// }
+///
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Hatch {
+    style: HatchStyle,
+    spacing: f64,
+    angle: f64,
+    line_width: f64,
+    foreground: [u8; 4],
+    background: Option<[u8; 4]>,
+}
+
+impl Hatch {
+    /// Creates a hatch with lines `spacing` pixels apart and `line_width` pixels wide, with the whole pattern rotated
+    /// by `angle` radians. Pixels between the lines are colored with `background`, or left untouched if it is `None`.
+    ///
+    /// Diagonal lines are measured along the rows of the pattern rather than across the lines, so that unrotated
+    /// diagonals stay one clean pixel wide.
+    pub fn new(
+        style: HatchStyle,
+        spacing: f64,
+        angle: f64,
+        line_width: f64,
+        foreground: [u8; 4],
+        background: Option<[u8; 4]>,
+    ) -> Self {
+        Self {
+            style,
+            spacing,
+            angle,
+            line_width,
+            foreground,
+            background,
+        }
+    }
+}
+
+impl Paint for Hatch {
+    fn color_at(&self, x: i32, y: i32) -> Option<[u8; 4]> {
+        if self.spacing <= 0.0 {
+            return self.background;
+        }
+
+        // Rotate the pixel into the space of the pattern, where the lines are straight again.
+        let (sin, cos) = self.angle.sin_cos();
+        let (x, y) = (x as f64, y as f64);
+        let (u, v) = (x * cos + y * sin, -x * sin + y * cos);
+        let on_line = |distance: f64| distance.rem_euclid(self.spacing) < self.line_width;
+
+        let horizontal = || on_line(v);
+        let vertical = || on_line(u);
+        let forward = || on_line(u + v);
+        let backward = || on_line(u - v);
+
+        let hit = match self.style {
+            HatchStyle::Horizontal => horizontal(),
+            HatchStyle::Vertical => vertical(),
+            HatchStyle::ForwardDiagonal => forward(),
+            HatchStyle::BackwardDiagonal => backward(),
+            HatchStyle::Cross => horizontal() || vertical(),
+            HatchStyle::DiagonalCross => forward() || backward(),
+        };
+
+        if hit {
+            Some(self.foreground)
+        } else {
+            self.background
+        }
+    }
+}
+
+/// A paint that repeats an 8x8 bitmap across the frame, like the fill patterns of classic paint programs.
+///
+/// Each byte of the pattern is one row, with the highest bit as the leftmost pixel. Set bits are colored with the
+/// foreground color, and cleared bits with the background color, or left untouched if there is none.
+/// The pattern is anchored to the top left of the frame, so neighbouring shapes line up seamlessly.
+///
+/// # Example
+///
+/// ```no_run
+/// use pixels::{Pixels, SurfaceTexture};
+/// use pixels_primitives::Stipple;
+/// use winit::dpi::LogicalSize;
+/// use winit::event_loop::{EventLoop};
+/// use std::error::Error;
+/// use winit::window::WindowBuilder;
+///
+/// const WIDTH: i32 = 800;
+/// const HEIGHT: i32 = 800;
+///
+/// fn main() -> Result<(), Box<dyn Error>> {
+///     let event_loop = EventLoop::new();
+///     let window = {
+///     let size = LogicalSize::new(WIDTH as f64, HEIGHT as f64);
+///     WindowBuilder::new()
+///         .with_title("Stipple Example")
+///         .with_inner_size(size)
+///         .with_min_inner_size(size)
+///         .build(&event_loop)
+///         .unwrap()
+///     };
+///
+///     let mut pixels = {
+///         let window_size = window.inner_size();
+///         let surface_texture = SurfaceTexture::new(window_size.width, window_size.height, &window);
+///         Pixels::new(WIDTH as u32, HEIGHT as u32, surface_texture)?
+///     };
+///
+///     let bricks = Stipple::new(
+///         [0xFF, 0x80, 0x80, 0x80, 0xFF, 0x08, 0x08, 0x08],
+///         [255, 255, 255, 255],
+///         None,
+///     );
+///
+///     pixels_primitives::circle_filled(pixels.get_frame(), WIDTH, 400.0, 400.0, 200.0, &bricks);
+///
+///     // Run your event loop here!
+///
+///     Ok(())
+/// }
+///
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Stipple {
+    pattern: [u8; 8],
+    foreground: [u8; 4],
+    background: Option<[u8; 4]>,
+}
+
+impl Stipple {
+    /// Creates a stipple from 8 rows of 8 bits each.
+    pub fn new(pattern: [u8; 8], foreground: [u8; 4], background: Option<[u8; 4]>) -> Self {
+        Self {
+            pattern,
+            foreground,
+            background,
+        }
+    }
+
+    /// A stipple where every other pixel is set, like a 50% gray.
+    pub fn checkerboard(foreground: [u8; 4], background: Option<[u8; 4]>) -> Self {
+        Self::new(
+            [0xAA, 0x55, 0xAA, 0x55, 0xAA, 0x55, 0xAA, 0x55],
+            foreground,
+            background,
+        )
+    }
+
+    /// A stipple with two staggered pixels set in every 4x4 block, like a 12.5% gray.
+    pub fn sparse_dots(foreground: [u8; 4], background: Option<[u8; 4]>) -> Self {
+        Self::new(
+            [0x88, 0x00, 0x22, 0x00, 0x88, 0x00, 0x22, 0x00],
+            foreground,
+            background,
+        )
+    }
+}
+
+impl Paint for Stipple {
+    fn color_at(&self, x: i32, y: i32) -> Option<[u8; 4]> {
+        let row = self.pattern[y.rem_euclid(8) as usize];
+        if row & (0x80 >> x.rem_euclid(8)) != 0 {
+            Some(self.foreground)
+        } else {
+            self.background
+        }
+    }
+}
@@ -0,0 +1,697 @@
+use std::f64::consts::PI;
+
+use crate::{curve, math, paint_line, scanline, FillRule, Paint};
+
+/// How the corners between two segments of a stroked [`Path`] are drawn.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LineJoin {
+    /// The outer edges are extended until they meet in a sharp point.
+    /// Falls back to [`LineJoin::Bevel`] when the point would be longer than the miter limit.
+    Miter,
+    /// The corner is rounded off with a circle the size of the stroke.
+    Round,
+    /// The corner is cut off with a straight line.
+    Bevel,
+}
+
+/// How the ends of an open, stroked [`Path`] are drawn.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LineCap {
+    /// The stroke stops exactly at the end of the path.
+    Butt,
+    /// The stroke ends in a half circle past the end of the path.
+    Round,
+    /// The stroke continues half of its width past the end of the path.
+    Square,
+}
+
+/// Describes how a [`Path`] is outlined by [`Path::stroke`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct StrokeStyle {
+    /// The width of the stroke in pixels. Strokes that are 1 pixel wide or less are drawn the same way as [`line`](fn@crate::line).
+    pub width: f64,
+    /// How corners between segments are drawn.
+    pub join: LineJoin,
+    /// How the ends of open subpaths are drawn.
+    pub cap: LineCap,
+    /// How many times longer than half of the stroke width a mitered corner can be before it is beveled instead.
+    pub miter_limit: f64,
+}
+
+impl Default for StrokeStyle {
+    fn default() -> Self {
+        Self {
+            width: 1.0,
+            join: LineJoin::Miter,
+            cap: LineCap::Butt,
+            miter_limit: 4.0,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum PathCommand {
+    MoveTo(f64, f64),
+    LineTo(f64, f64),
+    QuadTo(f64, f64, f64, f64),
+    CubicTo(f64, f64, f64, f64, f64, f64),
+    ArcTo {
+        radius_x: f64,
+        radius_y: f64,
+        x_axis_rotation: f64,
+        large_arc: bool,
+        sweep: bool,
+        x: f64,
+        y: f64,
+    },
+    Close,
+}
+
+/// A run of connected points, as produced by flattening a [`Path`].
+struct Subpath {
+    points: Vec<(f64, f64)>,
+    closed: bool,
+}
+
+/// A shape made out of lines and curves that can be filled or stroked onto a frame of pixels.
+///
+/// A path is built once with the `*_to` methods and can then be drawn as many times as needed.
+/// Curves are flattened into straight lines when the path is drawn, see [`Path::set_tolerance`].
+///
+/// # Example
+///
+/// ```no_run
+/// use pixels::{Pixels, SurfaceTexture};
+/// use pixels_primitives::{FillRule, Path, StrokeStyle};
+/// use winit::dpi::LogicalSize;
+/// use winit::event_loop::{EventLoop};
+/// use std::error::Error;
+/// use winit::window::WindowBuilder;
+///
+/// const WIDTH: i32 = 800;
+/// const HEIGHT: i32 = 800;
+///
+/// fn main() -> Result<(), Box<dyn Error>> {
+///     let event_loop = EventLoop::new();
+///     let window = {
+///     let size = LogicalSize::new(WIDTH as f64, HEIGHT as f64);
+///     WindowBuilder::new()
+///         .with_title("Path Example")
+///         .with_inner_size(size)
+///         .with_min_inner_size(size)
+///         .build(&event_loop)
+///         .unwrap()
+///     };
+///
+///     let mut pixels = {
+///         let window_size = window.inner_size();
+///         let surface_texture = SurfaceTexture::new(window_size.width, window_size.height, &window);
+///         Pixels::new(WIDTH as u32, HEIGHT as u32, surface_texture)?
+///     };
+///
+///     let mut path = Path::new();
+///     path.move_to(200.0, 200.0)
+///         .line_to(500.0, 200.0)
+///         .quad_to(600.0, 300.0, 500.0, 400.0)
+///         .cubic_to(400.0, 500.0, 300.0, 300.0, 200.0, 400.0)
+///         .close();
+///
+///     path.fill(pixels.get_frame(), WIDTH, FillRule::NonZero, &[0, 0, 255, 255]);
+///
+///     let style = StrokeStyle {
+///         width: 6.0,
+///         ..StrokeStyle::default()
+///     };
+///     path.stroke(pixels.get_frame(), WIDTH, &style, &[255, 255, 255, 255]);
+///
+///     // Run your event loop here!
+///
+///     Ok(())
+/// }
+///
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct Path {
+    commands: Vec<PathCommand>,
+    tolerance: f64,
+}
+
+impl Default for Path {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Path {
+    /// Creates an empty path with a flattening tolerance of a quarter of a pixel.
+    pub fn new() -> Self {
+        Self {
+            commands: Vec::new(),
+            tolerance: 0.25,
+        }
+    }
+
+    /// Returns true if no commands have been added to the path.
+    pub fn is_empty(&self) -> bool {
+        self.commands.is_empty()
+    }
+
+    /// Sets how many pixels a flattened curve is allowed to stray from the real curve.
+    ///
+    /// Tolerances below 0.01 are raised to 0.01, and NaN is replaced with the default of 0.25.
+    pub fn set_tolerance(&mut self, tolerance: f64) -> &mut Self {
+        self.tolerance = tolerance;
+        self
+    }
+
+    /// Starts a new subpath at the given point.
+    pub fn move_to(&mut self, x: f64, y: f64) -> &mut Self {
+        self.commands.push(PathCommand::MoveTo(x, y));
+        self
+    }
+
+    /// Adds a straight line from the current point to the given point.
+    pub fn line_to(&mut self, x: f64, y: f64) -> &mut Self {
+        self.commands.push(PathCommand::LineTo(x, y));
+        self
+    }
+
+    /// Adds a quadratic Bézier curve from the current point to (x, y).
+    pub fn quad_to(&mut self, control_x: f64, control_y: f64, x: f64, y: f64) -> &mut Self {
+        self.commands
+            .push(PathCommand::QuadTo(control_x, control_y, x, y));
+        self
+    }
+
+    /// Adds a cubic Bézier curve from the current point to (x, y).
+    pub fn cubic_to(
+        &mut self,
+        first_control_x: f64,
+        first_control_y: f64,
+        second_control_x: f64,
+        second_control_y: f64,
+        x: f64,
+        y: f64,
+    ) -> &mut Self {
+        self.commands.push(PathCommand::CubicTo(
+            first_control_x,
+            first_control_y,
+            second_control_x,
+            second_control_y,
+            x,
+            y,
+        ));
+        self
+    }
+
+    /// Adds an elliptical arc from the current point to (x, y), following the same rules as the SVG `A` command.
+    ///
+    /// The ellipse has the radii `radius_x` and `radius_y` and is rotated by `x_axis_rotation` radians. Of the four arcs
+    /// that can connect the two points, `large_arc` picks one that is longer than half of the ellipse, and `sweep` picks
+    /// one that runs clockwise on the screen. Radii that are too small to reach (x, y) are scaled up until they do.
+    #[allow(clippy::too_many_arguments)]
+    pub fn arc_to(
+        &mut self,
+        radius_x: f64,
+        radius_y: f64,
+        x_axis_rotation: f64,
+        large_arc: bool,
+        sweep: bool,
+        x: f64,
+        y: f64,
+    ) -> &mut Self {
+        self.commands.push(PathCommand::ArcTo {
+            radius_x,
+            radius_y,
+            x_axis_rotation,
+            large_arc,
+            sweep,
+            x,
+            y,
+        });
+        self
+    }
+
+    /// Closes the current subpath with a straight line back to where it started.
+    pub fn close(&mut self) -> &mut Self {
+        self.commands.push(PathCommand::Close);
+        self
+    }
+
+    /// Fills the inside of the path, with `fill_rule` deciding which overlapping areas count as inside.
+    ///
+    /// Subpaths that were not closed are filled as if they were.
+    pub fn fill<P: Paint + ?Sized>(
+        &self,
+        frame: &mut [u8],
+        canvas_width: i32,
+        fill_rule: FillRule,
+        paint: &P,
+    ) {
+        let canvas_height = frame.len() as i32 / 4 / canvas_width;
+        let contours: Vec<Vec<(f64, f64)>> = self
+            .flatten()
+            .into_iter()
+            .map(|subpath| subpath.points)
+            .collect();
+
+        scanline::fill_contours(
+            frame,
+            canvas_width,
+            canvas_height,
+            &contours,
+            fill_rule,
+            paint,
+        );
+    }
+
+    /// Draws the outline of the path using the given stroke style, colored by any paint, just like [`Path::fill`].
+    pub fn stroke<P: Paint + ?Sized>(
+        &self,
+        frame: &mut [u8],
+        canvas_width: i32,
+        style: &StrokeStyle,
+        paint: &P,
+    ) {
+        let subpaths = self.flatten();
+
+        if style.width <= 1.0 {
+            for subpath in &subpaths {
+                let mut points = subpath.points.clone();
+                if subpath.closed {
+                    points.push(points[0]);
+                }
+                for segment in points.windows(2) {
+                    let ((x0, y0), (x1, y1)) = (segment[0], segment[1]);
+                    paint_line(frame, canvas_width, x0, y0, x1, y1, paint);
+                }
+            }
+            return;
+        }
+
+        // Every segment, join and cap becomes its own small polygon. They all run in the same direction,
+        // so filling them together with the non-zero rule draws their union without gaps or holes.
+        let mut contours = Vec::new();
+        for subpath in &subpaths {
+            stroke_subpath(subpath, style, self.tolerance, &mut contours);
+        }
+        for contour in &mut contours {
+            if signed_area(contour) < 0.0 {
+                contour.reverse();
+            }
+        }
+
+        let canvas_height = frame.len() as i32 / 4 / canvas_width;
+        scanline::fill_contours(
+            frame,
+            canvas_width,
+            canvas_height,
+            &contours,
+            FillRule::NonZero,
+            paint,
+        );
+    }
+
+    /// Turns every command into straight lines, split up into subpaths.
+    fn flatten(&self) -> Vec<Subpath> {
+        let mut subpaths: Vec<Subpath> = Vec::new();
+        let mut points: Vec<(f64, f64)> = Vec::new();
+        let mut start = (0.0, 0.0);
+        let mut current = (0.0, 0.0);
+
+        for &command in &self.commands {
+            // Drawing without a current point starts a new subpath where the last one ended.
+            if points.is_empty() && !matches!(command, PathCommand::MoveTo(..) | PathCommand::Close)
+            {
+                start = current;
+                points.push(current);
+            }
+
+            match command {
+                PathCommand::MoveTo(x, y) => {
+                    finish_subpath(&mut points, false, &mut subpaths);
+                    start = (x, y);
+                    current = start;
+                    points.push(start);
+                }
+                PathCommand::LineTo(x, y) => {
+                    current = (x, y);
+                    points.push(current);
+                }
+                PathCommand::QuadTo(cx, cy, x, y) => {
+                    curve::flatten_quad(current, (cx, cy), (x, y), self.tolerance, &mut points);
+                    current = (x, y);
+                }
+                PathCommand::CubicTo(c1x, c1y, c2x, c2y, x, y) => {
+                    curve::flatten_cubic(
+                        current,
+                        (c1x, c1y),
+                        (c2x, c2y),
+                        (x, y),
+                        self.tolerance,
+                        &mut points,
+                    );
+                    current = (x, y);
+                }
+                PathCommand::ArcTo {
+                    radius_x,
+                    radius_y,
+                    x_axis_rotation,
+                    large_arc,
+                    sweep,
+                    x,
+                    y,
+                } => {
+                    flatten_arc(
+                        current,
+                        (radius_x, radius_y),
+                        x_axis_rotation,
+                        large_arc,
+                        sweep,
+                        (x, y),
+                        self.tolerance,
+                        &mut points,
+                    );
+                    current = (x, y);
+                }
+                PathCommand::Close => {
+                    finish_subpath(&mut points, true, &mut subpaths);
+                    current = start;
+                }
+            }
+        }
+        finish_subpath(&mut points, false, &mut subpaths);
+
+        subpaths
+    }
+}
+
+/// Moves the points collected so far into a new subpath, leaving `points` empty.
+fn finish_subpath(points: &mut Vec<(f64, f64)>, closed: bool, subpaths: &mut Vec<Subpath>) {
+    points.dedup();
+    if closed && points.len() > 1 && points.first() == points.last() {
+        points.pop();
+    }
+    if !points.is_empty() {
+        subpaths.push(Subpath {
+            points: std::mem::take(points),
+            closed,
+        });
+    }
+}
+
+/// Approximates an SVG style elliptical arc with straight lines, pushing every point after the start onto `points`.
+///
+/// This follows the endpoint to center conversion from the SVG specification (appendix F.6).
+#[allow(clippy::too_many_arguments)]
+fn flatten_arc(
+    start: (f64, f64),
+    radii: (f64, f64),
+    x_axis_rotation: f64,
+    large_arc: bool,
+    sweep: bool,
+    end: (f64, f64),
+    tolerance: f64,
+    points: &mut Vec<(f64, f64)>,
+) {
+    let (mut radius_x, mut radius_y) = (radii.0.abs(), radii.1.abs());
+    if start == end {
+        return;
+    }
+    if radius_x == 0.0 || radius_y == 0.0 {
+        points.push(end);
+        return;
+    }
+
+    let (sin, cos) = x_axis_rotation.sin_cos();
+    let (half_dx, half_dy) = ((start.0 - end.0) / 2.0, (start.1 - end.1) / 2.0);
+    let x1 = cos * half_dx + sin * half_dy;
+    let y1 = -sin * half_dx + cos * half_dy;
+
+    let lambda = (x1 * x1) / (radius_x * radius_x) + (y1 * y1) / (radius_y * radius_y);
+    if lambda > 1.0 {
+        radius_x *= lambda.sqrt();
+        radius_y *= lambda.sqrt();
+    }
+
+    let (rx2, ry2) = (radius_x * radius_x, radius_y * radius_y);
+    let numerator = (rx2 * ry2 - rx2 * y1 * y1 - ry2 * x1 * x1).max(0.0);
+    let mut coefficient = (numerator / (rx2 * y1 * y1 + ry2 * x1 * x1)).sqrt();
+    if large_arc == sweep {
+        coefficient = -coefficient;
+    }
+    let center_x1 = coefficient * radius_x * y1 / radius_y;
+    let center_y1 = -coefficient * radius_y * x1 / radius_x;
+    let center_x = cos * center_x1 - sin * center_y1 + (start.0 + end.0) / 2.0;
+    let center_y = sin * center_x1 + cos * center_y1 + (start.1 + end.1) / 2.0;
+
+    let start_angle = ((y1 - center_y1) / radius_y).atan2((x1 - center_x1) / radius_x);
+    let end_angle = ((-y1 - center_y1) / radius_y).atan2((-x1 - center_x1) / radius_x);
+    let mut sweep_angle = end_angle - start_angle;
+    if sweep && sweep_angle < 0.0 {
+        sweep_angle += 2.0 * PI;
+    } else if !sweep && sweep_angle > 0.0 {
+        sweep_angle -= 2.0 * PI;
+    }
+
+    let steps = arc_steps(radius_x.max(radius_y), sweep_angle.abs(), tolerance);
+    for step in 1..steps {
+        let angle = start_angle + sweep_angle * step as f64 / steps as f64;
+        let (x, y) = (radius_x * angle.cos(), radius_y * angle.sin());
+        points.push((center_x + cos * x - sin * y, center_y + sin * x + cos * y));
+    }
+    points.push(end);
+}
+
+/// How many straight lines an arc needs so that none of them strays further than `tolerance` from the arc.
+fn arc_steps(radius: f64, sweep_angle: f64, tolerance: f64) -> usize {
+    let tolerance = curve::clamp_tolerance(tolerance);
+    let step_angle = if tolerance >= radius {
+        PI / 2.0
+    } else {
+        2.0 * (1.0 - tolerance / radius).acos()
+    };
+    ((sweep_angle / step_angle).ceil() as usize).clamp(1, 1024)
+}
+
+/// Turns one flattened subpath into the polygons that cover its stroke.
+fn stroke_subpath(
+    subpath: &Subpath,
+    style: &StrokeStyle,
+    tolerance: f64,
+    contours: &mut Vec<Vec<(f64, f64)>>,
+) {
+    let half_width = style.width / 2.0;
+    let points = &subpath.points;
+
+    if points.len() == 1 {
+        // A subpath without length only shows up as its caps.
+        let (x, y) = points[0];
+        match style.cap {
+            LineCap::Butt => {}
+            LineCap::Round => contours.push(circle_points(x, y, half_width, tolerance)),
+            LineCap::Square => contours.push(vec![
+                (x - half_width, y - half_width),
+                (x + half_width, y - half_width),
+                (x + half_width, y + half_width),
+                (x - half_width, y + half_width),
+            ]),
+        }
+        return;
+    }
+
+    let segment_count = if subpath.closed {
+        points.len()
+    } else {
+        points.len() - 1
+    };
+    for i in 0..segment_count {
+        let (x0, y0) = points[i];
+        let (x1, y1) = points[(i + 1) % points.len()];
+        let (nx, ny) = normal((x0, y0), (x1, y1), half_width);
+        contours.push(vec![
+            (x0 + nx, y0 + ny),
+            (x1 + nx, y1 + ny),
+            (x1 - nx, y1 - ny),
+            (x0 - nx, y0 - ny),
+        ]);
+    }
+
+    let joined = if subpath.closed {
+        0..points.len()
+    } else {
+        1..points.len() - 1
+    };
+    for i in joined {
+        let previous = points[(i + points.len() - 1) % points.len()];
+        let next = points[(i + 1) % points.len()];
+        add_join(previous, points[i], next, style, tolerance, contours);
+    }
+
+    if !subpath.closed {
+        add_cap(points[1], points[0], style, tolerance, contours);
+        add_cap(
+            points[points.len() - 2],
+            points[points.len() - 1],
+            style,
+            tolerance,
+            contours,
+        );
+    }
+}
+
+fn add_join(
+    previous: (f64, f64),
+    corner: (f64, f64),
+    next: (f64, f64),
+    style: &StrokeStyle,
+    tolerance: f64,
+    contours: &mut Vec<Vec<(f64, f64)>>,
+) {
+    let half_width = style.width / 2.0;
+    let (x, y) = corner;
+
+    if style.join == LineJoin::Round {
+        contours.push(circle_points(x, y, half_width, tolerance));
+        return;
+    }
+
+    let incoming = (corner.0 - previous.0, corner.1 - previous.1);
+    let outgoing = (next.0 - corner.0, next.1 - corner.1);
+    let turn = incoming.0 * outgoing.1 - incoming.1 * outgoing.0;
+    if turn == 0.0 {
+        return;
+    }
+
+    // The join only has to fill the gap on the outside of the turn, the segments already overlap on the inside.
+    let side = if turn > 0.0 { -1.0 } else { 1.0 };
+    let (n0x, n0y) = normal(previous, corner, half_width * side);
+    let (n1x, n1y) = normal(corner, next, half_width * side);
+    let (outer0, outer1) = ((x + n0x, y + n0y), (x + n1x, y + n1y));
+
+    if style.join == LineJoin::Miter {
+        // The miter reaches 1 / cos(half of the angle between the normals) times half of the width away from the corner.
+        let cos_between = (n0x * n1x + n0y * n1y) / (half_width * half_width);
+        let half_cos = ((1.0 + cos_between) / 2.0).sqrt();
+        if half_cos > 0.0 && 1.0 / half_cos <= style.miter_limit {
+            let (mx, my) = (n0x + n1x, n0y + n1y);
+            let length = (mx * mx + my * my).sqrt();
+            let reach = half_width / half_cos;
+            let tip = (x + mx / length * reach, y + my / length * reach);
+            contours.push(vec![corner, outer0, tip, outer1]);
+            return;
+        }
+    }
+
+    contours.push(vec![corner, outer0, outer1]);
+}
+
+fn add_cap(
+    before: (f64, f64),
+    end: (f64, f64),
+    style: &StrokeStyle,
+    tolerance: f64,
+    contours: &mut Vec<Vec<(f64, f64)>>,
+) {
+    let half_width = style.width / 2.0;
+    let (x, y) = end;
+
+    match style.cap {
+        LineCap::Butt => {}
+        LineCap::Round => contours.push(circle_points(x, y, half_width, tolerance)),
+        LineCap::Square => {
+            let (nx, ny) = normal(before, end, half_width);
+            // Rotating the normal back gives the direction of the segment, scaled to half of the width.
+            let (dx, dy) = (ny, -nx);
+            contours.push(vec![
+                (x + nx, y + ny),
+                (x + nx + dx, y + ny + dy),
+                (x - nx + dx, y - ny + dy),
+                (x - nx, y - ny),
+            ]);
+        }
+    }
+}
+
+/// The normal of the segment from `start` to `end`, scaled to `length`.
+fn normal(start: (f64, f64), end: (f64, f64), length: f64) -> (f64, f64) {
+    let segment_length = math::distance(start.0, start.1, end.0, end.1);
+    if segment_length == 0.0 {
+        return (0.0, 0.0);
+    }
+    (
+        -(end.1 - start.1) / segment_length * length,
+        (end.0 - start.0) / segment_length * length,
+    )
+}
+
+fn circle_points(center_x: f64, center_y: f64, radius: f64, tolerance: f64) -> Vec<(f64, f64)> {
+    let steps = arc_steps(radius, 2.0 * PI, tolerance).max(8);
+    (0..steps)
+        .map(|step| {
+            let angle = 2.0 * PI * step as f64 / steps as f64;
+            (
+                center_x + radius * angle.cos(),
+                center_y + radius * angle.sin(),
+            )
+        })
+        .collect()
+}
+
+/// Twice the signed area of a polygon. Positive when the points run clockwise on the screen.
+fn signed_area(points: &[(f64, f64)]) -> f64 {
+    points
+        .iter()
+        .enumerate()
+        .map(|(i, &(x0, y0))| {
+            let (x1, y1) = points[(i + 1) % points.len()];
+            x0 * y1 - x1 * y0
+        })
+        .sum()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Colors every pixel by its column, so it is easy to tell that a pixel was painted at the right place.
+    struct Columns;
+
+    impl Paint for Columns {
+        fn color_at(&self, x: i32, _y: i32) -> Option<[u8; 4]> {
+            Some([x as u8, 0, 0, 255])
+        }
+    }
+
+    /// Strokes a horizontal line across an 8x8 frame and checks every drawn pixel against the paint.
+    fn stroke_with_columns(width: f64) -> usize {
+        let mut path = Path::new();
+        path.move_to(1.0, 4.0).line_to(7.0, 4.0);
+        let style = StrokeStyle {
+            width,
+            ..StrokeStyle::default()
+        };
+
+        let mut frame = vec![0; 8 * 8 * 4];
+        path.stroke(&mut frame, 8, &style, &Columns);
+
+        let mut drawn = 0;
+        for (i, pixel) in frame.chunks_exact(4).enumerate() {
+            if pixel[3] != 0 {
+                assert_eq!(pixel[0], (i % 8) as u8, "pixel ({}, {})", i % 8, i / 8);
+                drawn += 1;
+            }
+        }
+        drawn
+    }
+
+    #[test]
+    fn thin_strokes_use_the_paint() {
+        assert_eq!(stroke_with_columns(1.0), 6);
+    }
+
+    #[test]
+    fn wide_strokes_use_the_paint() {
+        assert!(stroke_with_columns(3.0) > 6);
+    }
+}
@@ -0,0 +1,104 @@
+use crate::{FillRule, Paint};
+
+/// A non-horizontal edge of a contour, stored top to bottom.
+struct Edge {
+    top_y: f64,
+    bottom_y: f64,
+    top_x: f64,
+    /// How much x changes for every step of 1 in y.
+    slope: f64,
+    /// +1 if the contour runs downwards along this edge, -1 if it runs upwards.
+    winding: i32,
+}
+
+/// Fills the area enclosed by one or more closed contours, using an active edge table.
+///
+/// Pixels are sampled at their integer coordinates, the same way `rect_filled` and `circle_filled` decide what
+/// is inside of a shape. Every contour is implicitly closed from its last point back to its first.
+pub fn fill_contours<C: AsRef<[(f64, f64)]>, P: Paint + ?Sized>(
+    frame: &mut [u8],
+    canvas_width: i32,
+    canvas_height: i32,
+    contours: &[C],
+    fill_rule: FillRule,
+    paint: &P,
+) {
+    let mut edges = Vec::new();
+    for contour in contours {
+        let contour = contour.as_ref();
+        for (i, &(ax, ay)) in contour.iter().enumerate() {
+            let (bx, by) = contour[(i + 1) % contour.len()];
+            if ay == by || ay.is_nan() || by.is_nan() {
+                continue;
+            }
+
+            let (top, bottom, winding) = if ay < by {
+                ((ax, ay), (bx, by), 1)
+            } else {
+                ((bx, by), (ax, ay), -1)
+            };
+            edges.push(Edge {
+                top_y: top.1,
+                bottom_y: bottom.1,
+                top_x: top.0,
+                slope: (bottom.0 - top.0) / (bottom.1 - top.1),
+                winding,
+            });
+        }
+    }
+    if edges.is_empty() {
+        return;
+    }
+    edges.sort_by(|a, b| a.top_y.total_cmp(&b.top_y));
+
+    // Edges are half-open in y, so the row where two edges meet is never counted twice.
+    let maximum_y = edges
+        .iter()
+        .map(|e| e.bottom_y)
+        .fold(f64::NEG_INFINITY, f64::max);
+    let first_row = (edges[0].top_y.ceil() as i32).max(0);
+    let last_row = (maximum_y.ceil() as i32 - 1).min(canvas_height - 1);
+
+    let mut next_edge = 0;
+    let mut active: Vec<&Edge> = Vec::new();
+    let mut crossings: Vec<(f64, i32)> = Vec::new();
+    for y in first_row..=last_row {
+        let scan_y = y as f64;
+
+        while next_edge < edges.len() && edges[next_edge].top_y <= scan_y {
+            active.push(&edges[next_edge]);
+            next_edge += 1;
+        }
+        active.retain(|e| e.bottom_y > scan_y);
+
+        crossings.clear();
+        crossings.extend(
+            active
+                .iter()
+                .map(|e| (e.top_x + (scan_y - e.top_y) * e.slope, e.winding)),
+        );
+        crossings.sort_by(|a, b| a.0.total_cmp(&b.0));
+
+        let mut winding = 0;
+        for (i, &(x, direction)) in crossings.iter().enumerate() {
+            winding += direction;
+            let inside = match fill_rule {
+                FillRule::EvenOdd => (i + 1) % 2 == 1,
+                FillRule::NonZero => winding != 0,
+            };
+            if !inside || i + 1 == crossings.len() {
+                continue;
+            }
+
+            let start_x = (x.ceil() as i32).max(0);
+            let end_x = (crossings[i + 1].0.ceil() as i32).min(canvas_width);
+            if start_x >= end_x {
+                continue;
+            }
+
+            let start = crate::get_starting_pixel_index(start_x, y, canvas_width);
+            let end = crate::get_starting_pixel_index(end_x, y, canvas_width);
+            paint.fill_span(&mut frame[start..end], start_x, y);
+        }
+    }
+}
@@ -0,0 +1,390 @@
+use std::time::Duration;
+
+use crate::{blit, BlitOptions, Image, Region};
+
+/// An image holding many sprites, such as the frames of an animation, along with where each of them is.
+///
+/// The frames are numbered in the order they were given, or left to right and top to bottom for a grid.
+///
+/// # Example
+///
+/// ```no_run
+/// use pixels::{Pixels, SurfaceTexture};
+/// use pixels_primitives::{BlitOptions, Image, SpriteSheet};
+/// use winit::dpi::LogicalSize;
+/// use winit::event_loop::{EventLoop};
+/// use std::error::Error;
+/// use winit::window::WindowBuilder;
+///
+/// const WIDTH: i32 = 800;
+/// const HEIGHT: i32 = 800;
+///
+/// fn main() -> Result<(), Box<dyn Error>> {
+///     let event_loop = EventLoop::new();
+///     let window = {
+///     let size = LogicalSize::new(WIDTH as f64, HEIGHT as f64);
+///     WindowBuilder::new()
+///         .with_title("Sprite Sheet Example")
+///         .with_inner_size(size)
+///         .with_min_inner_size(size)
+///         .build(&event_loop)
+///         .unwrap()
+///     };
+///
+///     let mut pixels = {
+///         let window_size = window.inner_size();
+///         let surface_texture = SurfaceTexture::new(window_size.width, window_size.height, &window);
+///         Pixels::new(WIDTH as u32, HEIGHT as u32, surface_texture)?
+///     };
+///
+///     // Four 16x16 frames side by side, each a different shade of red.
+///     let mut image = Image::new(64, 16);
+///     for frame in 0..4 {
+///         let shade = [64 + frame as u8 * 60, 0, 0, 255];
+///         pixels_primitives::rect_filled(image.pixels_mut(), 64, frame * 16, 0, frame * 16 + 15, 15, &shade);
+///     }
+///     let sheet = SpriteSheet::from_grid(image, 16, 16);
+///
+///     for index in 0..sheet.len() {
+///         sheet.draw(pixels.get_frame(), WIDTH, index, 100 + index as i32 * 20, 100, &BlitOptions::default());
+///     }
+///
+///     // Run your event loop here!
+///
+///     Ok(())
+/// }
+///
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SpriteSheet {
+    image: Image,
+    frames: Vec<Region>,
+}
+
+impl SpriteSheet {
+    /// Slices the image into a grid of frames that are all the same size.
+    ///
+    /// Cells that only partly fit at the right or bottom edge of the image are left out.
+    pub fn from_grid(image: Image, frame_width: u32, frame_height: u32) -> Self {
+        let mut frames = Vec::new();
+        if frame_width > 0 && frame_height > 0 {
+            let columns = image.width() / frame_width;
+            let rows = image.height() / frame_height;
+            for row in 0..rows {
+                for column in 0..columns {
+                    frames.push(Region::new(
+                        (column * frame_width) as i32,
+                        (row * frame_height) as i32,
+                        frame_width,
+                        frame_height,
+                    ));
+                }
+            }
+        }
+
+        Self { image, frames }
+    }
+
+    /// Uses the given regions of the image as frames, which may have different sizes.
+    ///
+    /// Regions are clipped to the image when they are drawn.
+    pub fn from_regions(image: Image, frames: Vec<Region>) -> Self {
+        Self { image, frames }
+    }
+
+    /// The image the frames are cut from.
+    pub fn image(&self) -> &Image {
+        &self.image
+    }
+
+    /// The regions of every frame, in order.
+    pub fn frames(&self) -> &[Region] {
+        &self.frames
+    }
+
+    /// The region of the frame at `index`, or `None` if there is no such frame.
+    pub fn frame(&self, index: usize) -> Option<Region> {
+        self.frames.get(index).copied()
+    }
+
+    /// How many frames the sheet has.
+    pub fn len(&self) -> usize {
+        self.frames.len()
+    }
+
+    /// Whether the sheet has no frames at all.
+    pub fn is_empty(&self) -> bool {
+        self.frames.is_empty()
+    }
+
+    /// Draws the frame at `index` with its top left corner at (x, y), just like [`crate::blit`].
+    ///
+    /// The source region of `options` is replaced by the frame's region. Nothing is drawn if there is no such frame.
+    pub fn draw(
+        &self,
+        frame: &mut [u8],
+        canvas_width: i32,
+        index: usize,
+        x: i32,
+        y: i32,
+        options: &BlitOptions,
+    ) {
+        let Some(region) = self.frame(index) else {
+            return;
+        };
+        let canvas_height = frame.len() as i32 / 4 / canvas_width;
+
+        blit::blit(
+            frame,
+            canvas_width,
+            canvas_height,
+            &self.image,
+            x,
+            y,
+            &BlitOptions {
+                source: Some(region),
+                ..*options
+            },
+        );
+    }
+}
+
+/// What an [`Animation`] does once it reaches its last frame.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AnimationMode {
+    /// Starts over from the first frame.
+    Loop,
+    /// Plays backwards to the first frame, then forwards again.
+    PingPong,
+    /// Stays on the last frame.
+    Once,
+}
+
+/// A sequence of sprite sheet frames that are each shown for the same amount of time.
+///
+/// An animation doesn't keep track of time itself. Instead, the current frame is picked from how long the animation
+/// has been playing, so the same animation can be shared by any number of sprites.
+///
+/// # Example
+///
+/// ```no_run
+/// use pixels::{Pixels, SurfaceTexture};
+/// use pixels_primitives::{Animation, AnimationMode, BlitOptions, Image, SpriteSheet};
+/// use std::time::{Duration, Instant};
+/// use winit::dpi::LogicalSize;
+/// use winit::event_loop::{EventLoop};
+/// use std::error::Error;
+/// use winit::window::WindowBuilder;
+///
+/// const WIDTH: i32 = 800;
+/// const HEIGHT: i32 = 800;
+///
+/// fn main() -> Result<(), Box<dyn Error>> {
+///     let event_loop = EventLoop::new();
+///     let window = {
+///     let size = LogicalSize::new(WIDTH as f64, HEIGHT as f64);
+///     WindowBuilder::new()
+///         .with_title("Animation Example")
+///         .with_inner_size(size)
+///         .with_min_inner_size(size)
+///         .build(&event_loop)
+///         .unwrap()
+///     };
+///
+///     let mut pixels = {
+///         let window_size = window.inner_size();
+///         let surface_texture = SurfaceTexture::new(window_size.width, window_size.height, &window);
+///         Pixels::new(WIDTH as u32, HEIGHT as u32, surface_texture)?
+///     };
+///
+///     let sheet = SpriteSheet::from_grid(Image::new(64, 16), 16, 16);
+///     let walk = Animation::new(vec![0, 1, 2, 3], Duration::from_millis(100), AnimationMode::Loop);
+///     let started = Instant::now();
+///
+///     // Call this every time the window is redrawn.
+///     let index = walk.frame_at(started.elapsed());
+///     sheet.draw(pixels.get_frame(), WIDTH, index, 100, 100, &BlitOptions::default());
+///
+///     // Run your event loop here!
+///
+///     Ok(())
+/// }
+///
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Animation {
+    frames: Vec<usize>,
+    frame_duration: Duration,
+    mode: AnimationMode,
+}
+
+impl Animation {
+    /// Creates an animation that shows the sprite sheet frames in `frames` in order, each for `frame_duration`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `frames` is empty or `frame_duration` is zero.
+    pub fn new(frames: Vec<usize>, frame_duration: Duration, mode: AnimationMode) -> Self {
+        assert!(!frames.is_empty(), "an animation needs at least one frame");
+        assert!(
+            !frame_duration.is_zero(),
+            "an animation's frames need to last longer than zero"
+        );
+
+        Self {
+            frames,
+            frame_duration,
+            mode,
+        }
+    }
+
+    /// The sprite sheet frames of the animation, in order.
+    pub fn frames(&self) -> &[usize] {
+        &self.frames
+    }
+
+    /// How long each frame is shown for.
+    pub fn frame_duration(&self) -> Duration {
+        self.frame_duration
+    }
+
+    /// What the animation does once it reaches its last frame.
+    pub fn mode(&self) -> AnimationMode {
+        self.mode
+    }
+
+    /// How long it takes to play the animation once. A ping-pong animation is played once it is back at its first frame.
+    pub fn duration(&self) -> Duration {
+        self.frame_duration * self.steps() as u32
+    }
+
+    /// Whether an animation that plays only once has reached its last frame.
+    /// Looping and ping-pong animations are never finished.
+    pub fn is_finished(&self, elapsed: Duration) -> bool {
+        self.mode == AnimationMode::Once && self.step(elapsed) >= self.frames.len() - 1
+    }
+
+    /// The sprite sheet frame to show after the animation has been playing for `elapsed`.
+    pub fn frame_at(&self, elapsed: Duration) -> usize {
+        let step = self.step(elapsed);
+        let last = self.frames.len() - 1;
+        let position = match self.mode {
+            AnimationMode::Loop => step % self.frames.len(),
+            AnimationMode::Once => step.min(last),
+            AnimationMode::PingPong => {
+                let step = step % self.steps();
+                if step <= last {
+                    step
+                } else {
+                    2 * last - step
+                }
+            }
+        };
+        self.frames[position]
+    }
+
+    /// Draws the frame to show after `elapsed` from `sheet` with its top left corner at (x, y).
+    #[allow(clippy::too_many_arguments)]
+    pub fn draw(
+        &self,
+        sheet: &SpriteSheet,
+        frame: &mut [u8],
+        canvas_width: i32,
+        elapsed: Duration,
+        x: i32,
+        y: i32,
+        options: &BlitOptions,
+    ) {
+        sheet.draw(frame, canvas_width, self.frame_at(elapsed), x, y, options);
+    }
+
+    /// How many frame durations have fully passed.
+    fn step(&self, elapsed: Duration) -> usize {
+        (elapsed.as_nanos() / self.frame_duration.as_nanos()).min(usize::MAX as u128) as usize
+    }
+
+    /// How many frames are shown in one pass of the animation, counting each frame of a ping-pong twice
+    /// except for the first and last.
+    fn steps(&self) -> usize {
+        match self.mode {
+            AnimationMode::PingPong if self.frames.len() > 1 => 2 * (self.frames.len() - 1),
+            _ => self.frames.len(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// The frames an animation of frames 10, 11 and 12 shows over its first `steps` frame durations.
+    fn sequence(mode: AnimationMode, steps: u64) -> Vec<usize> {
+        let animation = Animation::new(vec![10, 11, 12], Duration::from_millis(100), mode);
+        (0..steps)
+            .map(|step| animation.frame_at(Duration::from_millis(step * 100 + 50)))
+            .collect()
+    }
+
+    #[test]
+    fn ping_pong_does_not_repeat_the_end_frames() {
+        assert_eq!(
+            sequence(AnimationMode::PingPong, 9),
+            [10, 11, 12, 11, 10, 11, 12, 11, 10]
+        );
+    }
+
+    #[test]
+    fn loop_wraps_around_to_the_first_frame() {
+        assert_eq!(
+            sequence(AnimationMode::Loop, 7),
+            [10, 11, 12, 10, 11, 12, 10]
+        );
+    }
+
+    #[test]
+    fn once_stays_on_the_last_frame() {
+        assert_eq!(sequence(AnimationMode::Once, 6), [10, 11, 12, 12, 12, 12]);
+
+        let animation = Animation::new(
+            vec![10, 11, 12],
+            Duration::from_millis(100),
+            AnimationMode::Once,
+        );
+        assert!(!animation.is_finished(Duration::ZERO));
+        assert!(!animation.is_finished(Duration::from_millis(199)));
+        assert!(animation.is_finished(Duration::from_millis(200)));
+        assert!(animation.is_finished(Duration::MAX));
+        assert_eq!(animation.frame_at(Duration::MAX), 12);
+    }
+
+    #[test]
+    fn only_once_ever_finishes() {
+        for mode in [AnimationMode::Loop, AnimationMode::PingPong] {
+            let animation = Animation::new(vec![10, 11, 12], Duration::from_millis(100), mode);
+            assert!(
+                !animation.is_finished(Duration::from_secs(60)),
+                "{:?}",
+                mode
+            );
+        }
+    }
+
+    #[test]
+    fn single_frame_animations_always_show_that_frame() {
+        for mode in [
+            AnimationMode::Loop,
+            AnimationMode::PingPong,
+            AnimationMode::Once,
+        ] {
+            let animation = Animation::new(vec![7], Duration::from_millis(100), mode);
+            for elapsed in [0, 50, 100, 250, 10_000] {
+                assert_eq!(
+                    animation.frame_at(Duration::from_millis(elapsed)),
+                    7,
+                    "{:?}",
+                    mode
+                );
+            }
+        }
+    }
+}
@@ -0,0 +1,441 @@
+use std::error::Error;
+use std::fmt;
+use std::str::FromStr;
+
+use crate::Path;
+
+/// The reasons SVG path data can fail to parse.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SvgPathError {
+    /// The path data did not start with a `M` or `m` command.
+    MissingMoveTo,
+    /// A character that is not a command was found where a command was expected.
+    UnknownCommand {
+        /// The character that was found.
+        character: char,
+        /// The byte offset of the character in the path data.
+        position: usize,
+    },
+    /// A command ran out of numbers before all of its arguments were read.
+    ExpectedNumber {
+        /// The byte offset where the number was expected.
+        position: usize,
+    },
+    /// An arc flag was something other than `0` or `1`.
+    ExpectedFlag {
+        /// The byte offset where the flag was expected.
+        position: usize,
+    },
+}
+
+impl fmt::Display for SvgPathError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            SvgPathError::MissingMoveTo => write!(f, "path data must start with a move command"),
+            SvgPathError::UnknownCommand {
+                character,
+                position,
+            } => write!(f, "unknown path command '{}' at {}", character, position),
+            SvgPathError::ExpectedNumber { position } => {
+                write!(f, "expected a number at {}", position)
+            }
+            SvgPathError::ExpectedFlag { position } => {
+                write!(f, "expected an arc flag at {}", position)
+            }
+        }
+    }
+}
+
+impl Error for SvgPathError {}
+
+impl FromStr for Path {
+    type Err = SvgPathError;
+
+    fn from_str(data: &str) -> Result<Self, Self::Err> {
+        Path::from_svg(data)
+    }
+}
+
+impl Path {
+    /// Parses the path data of an SVG `d` attribute, such as `"M 10 10 h 80 v 80 h -80 Z"`.
+    ///
+    /// Every command is supported, in both its absolute (upper case) and relative (lower case) form:
+    /// `M`, `L`, `H`, `V`, `C`, `S`, `Q`, `T`, `A` and `Z`. The same path can also be parsed with [`str::parse`].
+    pub fn from_svg(data: &str) -> Result<Path, SvgPathError> {
+        let mut parser = Parser {
+            bytes: data.as_bytes(),
+            position: 0,
+        };
+        let mut path = Path::new();
+
+        let mut current = (0.0, 0.0);
+        let mut start = (0.0, 0.0);
+        // The last control point of the previous curve, which the smooth commands reflect.
+        let mut last_cubic_control: Option<(f64, f64)> = None;
+        let mut last_quad_control: Option<(f64, f64)> = None;
+        let mut command: Option<u8> = None;
+
+        loop {
+            parser.skip_separators();
+            let Some(next) = parser.peek() else {
+                break;
+            };
+
+            if next.is_ascii_alphabetic() {
+                parser.position += 1;
+                if command.is_none() && !matches!(next, b'M' | b'm') {
+                    return Err(SvgPathError::MissingMoveTo);
+                }
+                command = Some(next);
+            } else if command.is_none() {
+                return Err(SvgPathError::MissingMoveTo);
+            } else if matches!(command, Some(b'Z' | b'z')) {
+                // Numbers can't follow a close command, only another command can.
+                return Err(SvgPathError::UnknownCommand {
+                    character: data[parser.position..].chars().next().unwrap_or_default(),
+                    position: parser.position,
+                });
+            }
+
+            let letter = command.unwrap_or(b'M');
+            let relative = letter.is_ascii_lowercase();
+            let (mut cubic_control, mut quad_control) = (None, None);
+
+            match letter.to_ascii_uppercase() {
+                b'M' => {
+                    current = resolve(relative, current, parser.pair()?);
+                    start = current;
+                    path.move_to(current.0, current.1);
+                    // Any coordinates after the first pair of a move command are treated as lines.
+                    command = Some(if relative { b'l' } else { b'L' });
+                }
+                b'L' => {
+                    current = resolve(relative, current, parser.pair()?);
+                    path.line_to(current.0, current.1);
+                }
+                b'H' => {
+                    let x = parser.number()?;
+                    current.0 = if relative { current.0 + x } else { x };
+                    path.line_to(current.0, current.1);
+                }
+                b'V' => {
+                    let y = parser.number()?;
+                    current.1 = if relative { current.1 + y } else { y };
+                    path.line_to(current.0, current.1);
+                }
+                b'C' => {
+                    let first = resolve(relative, current, parser.pair()?);
+                    let second = resolve(relative, current, parser.pair()?);
+                    let end = resolve(relative, current, parser.pair()?);
+                    path.cubic_to(first.0, first.1, second.0, second.1, end.0, end.1);
+                    cubic_control = Some(second);
+                    current = end;
+                }
+                b'S' => {
+                    let first = reflect(last_cubic_control, current);
+                    let second = resolve(relative, current, parser.pair()?);
+                    let end = resolve(relative, current, parser.pair()?);
+                    path.cubic_to(first.0, first.1, second.0, second.1, end.0, end.1);
+                    cubic_control = Some(second);
+                    current = end;
+                }
+                b'Q' => {
+                    let control = resolve(relative, current, parser.pair()?);
+                    let end = resolve(relative, current, parser.pair()?);
+                    path.quad_to(control.0, control.1, end.0, end.1);
+                    quad_control = Some(control);
+                    current = end;
+                }
+                b'T' => {
+                    let control = reflect(last_quad_control, current);
+                    let end = resolve(relative, current, parser.pair()?);
+                    path.quad_to(control.0, control.1, end.0, end.1);
+                    quad_control = Some(control);
+                    current = end;
+                }
+                b'A' => {
+                    let radius_x = parser.number()?;
+                    let radius_y = parser.number()?;
+                    let x_axis_rotation = parser.number()?;
+                    let large_arc = parser.flag()?;
+                    let sweep = parser.flag()?;
+                    let end = resolve(relative, current, parser.pair()?);
+                    path.arc_to(
+                        radius_x,
+                        radius_y,
+                        x_axis_rotation.to_radians(),
+                        large_arc,
+                        sweep,
+                        end.0,
+                        end.1,
+                    );
+                    current = end;
+                }
+                b'Z' => {
+                    path.close();
+                    current = start;
+                }
+                _ => {
+                    return Err(SvgPathError::UnknownCommand {
+                        character: letter as char,
+                        position: parser.position - 1,
+                    })
+                }
+            }
+
+            last_cubic_control = cubic_control;
+            last_quad_control = quad_control;
+        }
+
+        Ok(path)
+    }
+}
+
+/// Turns a relative point into an absolute one.
+fn resolve(relative: bool, current: (f64, f64), (x, y): (f64, f64)) -> (f64, f64) {
+    if relative {
+        (current.0 + x, current.1 + y)
+    } else {
+        (x, y)
+    }
+}
+
+/// Mirrors the previous control point around the current point, or uses the current point if there was none.
+fn reflect(control: Option<(f64, f64)>, current: (f64, f64)) -> (f64, f64) {
+    match control {
+        Some((x, y)) => (2.0 * current.0 - x, 2.0 * current.1 - y),
+        None => current,
+    }
+}
+
+struct Parser<'a> {
+    bytes: &'a [u8],
+    position: usize,
+}
+
+impl Parser<'_> {
+    fn peek(&self) -> Option<u8> {
+        self.bytes.get(self.position).copied()
+    }
+
+    fn skip_separators(&mut self) {
+        while let Some(b' ' | b'\t' | b'\n' | b'\r' | b'\x0C' | b',') = self.peek() {
+            self.position += 1;
+        }
+    }
+
+    fn pair(&mut self) -> Result<(f64, f64), SvgPathError> {
+        Ok((self.number()?, self.number()?))
+    }
+
+    /// Reads a number, which can directly follow the previous one, as in `"10-5.5.5"`.
+    fn number(&mut self) -> Result<f64, SvgPathError> {
+        self.skip_separators();
+        let start = self.position;
+        let digits = |parser: &mut Self| {
+            let before = parser.position;
+            while let Some(b'0'..=b'9') = parser.peek() {
+                parser.position += 1;
+            }
+            parser.position > before
+        };
+
+        if let Some(b'+' | b'-') = self.peek() {
+            self.position += 1;
+        }
+        let mut has_digits = digits(self);
+        if self.peek() == Some(b'.') {
+            self.position += 1;
+            has_digits |= digits(self);
+        }
+        if !has_digits {
+            self.position = start;
+            return Err(SvgPathError::ExpectedNumber { position: start });
+        }
+
+        // Only treat an 'e' as an exponent if digits follow it, so "1e" stays a number followed by a command.
+        if let Some(b'e' | b'E') = self.peek() {
+            let before_exponent = self.position;
+            self.position += 1;
+            if let Some(b'+' | b'-') = self.peek() {
+                self.position += 1;
+            }
+            if !digits(self) {
+                self.position = before_exponent;
+            }
+        }
+
+        std::str::from_utf8(&self.bytes[start..self.position])
+            .ok()
+            .and_then(|text| text.parse().ok())
+            .ok_or(SvgPathError::ExpectedNumber { position: start })
+    }
+
+    /// Reads an arc flag, which is a single `0` or `1` that does not need a separator after it.
+    fn flag(&mut self) -> Result<bool, SvgPathError> {
+        self.skip_separators();
+        let flag = match self.peek() {
+            Some(b'0') => false,
+            Some(b'1') => true,
+            _ => {
+                return Err(SvgPathError::ExpectedFlag {
+                    position: self.position,
+                })
+            }
+        };
+        self.position += 1;
+        Ok(flag)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn parse(data: &str) -> Path {
+        Path::from_svg(data).unwrap()
+    }
+
+    #[test]
+    fn coordinates_after_a_move_are_lines() {
+        let mut expected = Path::new();
+        expected
+            .move_to(10.0, 20.0)
+            .line_to(30.0, 40.0)
+            .line_to(50.0, 60.0);
+        assert_eq!(parse("M 10 20 30 40 50 60"), expected);
+
+        let mut expected = Path::new();
+        expected.move_to(10.0, 20.0).line_to(15.0, 25.0);
+        assert_eq!(parse("m 10 20 5 5"), expected);
+    }
+
+    #[test]
+    fn horizontal_lines_repeat() {
+        let mut expected = Path::new();
+        expected
+            .move_to(0.0, 5.0)
+            .line_to(10.0, 5.0)
+            .line_to(20.0, 5.0);
+        assert_eq!(parse("M 0 5 H 10 20"), expected);
+
+        let mut expected = Path::new();
+        expected
+            .move_to(0.0, 5.0)
+            .line_to(10.0, 5.0)
+            .line_to(30.0, 5.0);
+        assert_eq!(parse("M 0 5 h 10 20"), expected);
+    }
+
+    #[test]
+    fn numbers_can_be_packed_together() {
+        let mut expected = Path::new();
+        expected.move_to(10.0, -5.5).line_to(0.5, -10.0);
+        assert_eq!(parse("M10-5.5.5-1e1"), expected);
+
+        let mut expected = Path::new();
+        expected.move_to(100.0, 25.0).line_to(0.02, 3.0);
+        assert_eq!(parse("M1e2,2.5E+1L2e-2 3"), expected);
+    }
+
+    #[test]
+    fn an_e_without_digits_is_not_an_exponent() {
+        // The 'e' is read as a command instead.
+        assert!(matches!(
+            Path::from_svg("M1 2e"),
+            Err(SvgPathError::UnknownCommand {
+                character: 'e',
+                position: 4
+            })
+        ));
+    }
+
+    #[test]
+    fn smooth_curves_after_other_commands_use_the_current_point() {
+        let mut expected = Path::new();
+        expected
+            .move_to(0.0, 0.0)
+            .line_to(10.0, 0.0)
+            .cubic_to(10.0, 0.0, 20.0, 10.0, 30.0, 0.0);
+        assert_eq!(parse("M 0 0 L 10 0 S 20 10 30 0"), expected);
+
+        let mut expected = Path::new();
+        expected
+            .move_to(0.0, 0.0)
+            .line_to(10.0, 0.0)
+            .quad_to(10.0, 0.0, 30.0, 0.0);
+        assert_eq!(parse("M 0 0 L 10 0 T 30 0"), expected);
+
+        // A quadratic curve's control point is not reflected by a cubic smooth curve, and the other way around.
+        let mut expected = Path::new();
+        expected
+            .move_to(0.0, 0.0)
+            .quad_to(5.0, 5.0, 10.0, 0.0)
+            .cubic_to(10.0, 0.0, 20.0, 10.0, 30.0, 0.0);
+        assert_eq!(parse("M 0 0 Q 5 5 10 0 S 20 10 30 0"), expected);
+    }
+
+    #[test]
+    fn smooth_curves_reflect_the_previous_control_point() {
+        let mut expected = Path::new();
+        expected
+            .move_to(0.0, 0.0)
+            .cubic_to(0.0, 10.0, 10.0, 10.0, 10.0, 0.0)
+            .cubic_to(10.0, -10.0, 20.0, -10.0, 20.0, 0.0);
+        assert_eq!(parse("M 0 0 C 0 10 10 10 10 0 S 20 -10 20 0"), expected);
+
+        let mut expected = Path::new();
+        expected
+            .move_to(0.0, 0.0)
+            .quad_to(5.0, 10.0, 10.0, 0.0)
+            .quad_to(15.0, -10.0, 20.0, 0.0);
+        assert_eq!(parse("M 0 0 Q 5 10 10 0 T 20 0"), expected);
+    }
+
+    #[test]
+    fn arc_flags_need_no_separators() {
+        let mut expected = Path::new();
+        expected
+            .move_to(0.0, 0.0)
+            .arc_to(5.0, 5.0, 0.0, true, false, 10.0, 10.0);
+        assert_eq!(parse("M0 0A5 5 0 1010 10"), expected);
+        assert_eq!(parse("M0 0A5,5,0,1,0,10,10"), expected);
+    }
+
+    #[test]
+    fn paths_must_start_with_a_move() {
+        assert_eq!(Path::from_svg("L 10 10"), Err(SvgPathError::MissingMoveTo));
+        assert_eq!(Path::from_svg("10 10"), Err(SvgPathError::MissingMoveTo));
+    }
+
+    #[test]
+    fn errors_report_where_they_happened() {
+        assert_eq!(
+            Path::from_svg("M 0 0 X 1"),
+            Err(SvgPathError::UnknownCommand {
+                character: 'X',
+                position: 6
+            })
+        );
+        // Only a command can follow a close command.
+        assert_eq!(
+            Path::from_svg("M 0 0 Z 5"),
+            Err(SvgPathError::UnknownCommand {
+                character: '5',
+                position: 8
+            })
+        );
+        assert_eq!(
+            Path::from_svg("M 0 0 L 10"),
+            Err(SvgPathError::ExpectedNumber { position: 10 })
+        );
+        assert_eq!(
+            Path::from_svg("M 0 0 L 10 ,"),
+            Err(SvgPathError::ExpectedNumber { position: 12 })
+        );
+        assert_eq!(
+            Path::from_svg("M 0 0 A 5 5 0 2 0 10 10"),
+            Err(SvgPathError::ExpectedFlag { position: 14 })
+        );
+    }
+}
@@ -0,0 +1,226 @@
+use crate::{BlitOptions, SpriteSheet};
+
+/// A grid of tiles in one or more layers, drawn from the frames of a tileset [`SpriteSheet`].
+///
+/// Every tile is an index into the tileset's frames, or `None` to leave that cell of the layer empty. Layers are drawn
+/// in the order they were added, so later layers are drawn on top of earlier ones.
+///
+/// # Example
+///
+/// ```no_run
+/// use pixels::{Pixels, SurfaceTexture};
+/// use pixels_primitives::{BlitOptions, Image, SpriteSheet, Tilemap};
+/// use winit::dpi::LogicalSize;
+/// use winit::event_loop::{EventLoop};
+/// use std::error::Error;
+/// use winit::window::WindowBuilder;
+///
+/// const WIDTH: i32 = 800;
+/// const HEIGHT: i32 = 800;
+///
+/// fn main() -> Result<(), Box<dyn Error>> {
+///     let event_loop = EventLoop::new();
+///     let window = {
+///     let size = LogicalSize::new(WIDTH as f64, HEIGHT as f64);
+///     WindowBuilder::new()
+///         .with_title("Tilemap Example")
+///         .with_inner_size(size)
+///         .with_min_inner_size(size)
+///         .build(&event_loop)
+///         .unwrap()
+///     };
+///
+///     let mut pixels = {
+///         let window_size = window.inner_size();
+///         let surface_texture = SurfaceTexture::new(window_size.width, window_size.height, &window);
+///         Pixels::new(WIDTH as u32, HEIGHT as u32, surface_texture)?
+///     };
+///
+///     // A green and a blue 32x32 tile.
+///     let mut image = Image::new(64, 32);
+///     pixels_primitives::rect_filled(image.pixels_mut(), 64, 0, 0, 31, 31, &[0, 160, 0, 255]);
+///     pixels_primitives::rect_filled(image.pixels_mut(), 64, 32, 0, 63, 31, &[0, 0, 200, 255]);
+///     let tileset = SpriteSheet::from_grid(image, 32, 32);
+///
+///     // A 40x40 map of grass with a river running through it.
+///     let mut map = Tilemap::new(40, 40, 32, 32);
+///     let ground = map.add_layer(vec![Some(0); 40 * 40]);
+///     for row in 0..40 {
+///         map.set_tile(ground, 20, row, Some(1));
+///     }
+///
+///     // Scrolled 300 pixels to the right, so the river is in view.
+///     map.draw(&tileset, pixels.get_frame(), WIDTH, 300, 0, &BlitOptions::default());
+///
+///     // Run your event loop here!
+///
+///     Ok(())
+/// }
+///
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Tilemap {
+    columns: u32,
+    rows: u32,
+    tile_width: u32,
+    tile_height: u32,
+    layers: Vec<Vec<Option<usize>>>,
+}
+
+impl Tilemap {
+    /// Creates a map that is `columns` by `rows` tiles of `tile_width` by `tile_height` pixels, without any layers.
+    pub fn new(columns: u32, rows: u32, tile_width: u32, tile_height: u32) -> Self {
+        Self {
+            columns,
+            rows,
+            tile_width,
+            tile_height,
+            layers: Vec::new(),
+        }
+    }
+
+    /// Adds a layer on top of the existing ones and returns its index.
+    /// The tiles are given row by row, starting at the top left of the map.
+    ///
+    /// # Panics
+    ///
+    /// Panics if there isn't exactly one tile for every cell of the map.
+    pub fn add_layer(&mut self, tiles: Vec<Option<usize>>) -> usize {
+        assert_eq!(
+            tiles.len(),
+            self.columns as usize * self.rows as usize,
+            "a layer needs exactly one tile for every cell of the map"
+        );
+        self.layers.push(tiles);
+        self.layers.len() - 1
+    }
+
+    /// How many tiles wide the map is.
+    pub fn columns(&self) -> u32 {
+        self.columns
+    }
+
+    /// How many tiles tall the map is.
+    pub fn rows(&self) -> u32 {
+        self.rows
+    }
+
+    /// The size of a single tile, in pixels.
+    pub fn tile_size(&self) -> (u32, u32) {
+        (self.tile_width, self.tile_height)
+    }
+
+    /// The size of the whole map, in pixels.
+    pub fn pixel_size(&self) -> (u32, u32) {
+        (self.columns * self.tile_width, self.rows * self.tile_height)
+    }
+
+    /// How many layers the map has.
+    pub fn layers(&self) -> usize {
+        self.layers.len()
+    }
+
+    /// The tile at (column, row) of a layer, or `None` if the cell is empty or outside of the map.
+    pub fn tile(&self, layer: usize, column: u32, row: u32) -> Option<usize> {
+        let index = self.index(column, row)?;
+        *self.layers.get(layer)?.get(index)?
+    }
+
+    /// Changes the tile at (column, row) of a layer. Cells outside of the map are ignored.
+    pub fn set_tile(&mut self, layer: usize, column: u32, row: u32, tile: Option<usize>) {
+        let Some(index) = self.index(column, row) else {
+            return;
+        };
+        if let Some(cell) = self
+            .layers
+            .get_mut(layer)
+            .and_then(|tiles| tiles.get_mut(index))
+        {
+            *cell = tile;
+        }
+    }
+
+    /// Draws every layer of the map, with the pixel at (scroll_x, scroll_y) of the map in the top left corner of
+    /// the frame.
+    ///
+    /// Only the tiles that are at least partly visible are drawn, and the ones along the edges of the frame are clipped.
+    pub fn draw(
+        &self,
+        tileset: &SpriteSheet,
+        frame: &mut [u8],
+        canvas_width: i32,
+        scroll_x: i32,
+        scroll_y: i32,
+        options: &BlitOptions,
+    ) {
+        for layer in 0..self.layers.len() {
+            self.draw_layer(
+                layer,
+                tileset,
+                frame,
+                canvas_width,
+                scroll_x,
+                scroll_y,
+                options,
+            );
+        }
+    }
+
+    /// Draws a single layer of the map, scrolled the same way as [`Tilemap::draw`].
+    #[allow(clippy::too_many_arguments)]
+    pub fn draw_layer(
+        &self,
+        layer: usize,
+        tileset: &SpriteSheet,
+        frame: &mut [u8],
+        canvas_width: i32,
+        scroll_x: i32,
+        scroll_y: i32,
+        options: &BlitOptions,
+    ) {
+        let Some(tiles) = self.layers.get(layer) else {
+            return;
+        };
+        if self.tile_width == 0 || self.tile_height == 0 || canvas_width <= 0 {
+            return;
+        }
+        let canvas_height = frame.len() as i32 / 4 / canvas_width;
+
+        // The range of tiles that overlap the frame, clamped to the map.
+        let (tile_width, tile_height) = (self.tile_width as i64, self.tile_height as i64);
+        let (scroll_x, scroll_y) = (scroll_x as i64, scroll_y as i64);
+        let first_column = scroll_x.div_euclid(tile_width).max(0);
+        let first_row = scroll_y.div_euclid(tile_height).max(0);
+        let last_column = (scroll_x + canvas_width as i64 - 1)
+            .div_euclid(tile_width)
+            .min(self.columns as i64 - 1);
+        let last_row = (scroll_y + canvas_height as i64 - 1)
+            .div_euclid(tile_height)
+            .min(self.rows as i64 - 1);
+
+        for row in first_row..=last_row {
+            for column in first_column..=last_column {
+                let Some(tile) = tiles[row as usize * self.columns as usize + column as usize]
+                else {
+                    continue;
+                };
+                tileset.draw(
+                    frame,
+                    canvas_width,
+                    tile,
+                    (column * tile_width - scroll_x) as i32,
+                    (row * tile_height - scroll_y) as i32,
+                    options,
+                );
+            }
+        }
+    }
+
+    /// The position of a cell in a layer, or `None` if it is outside of the map.
+    fn index(&self, column: u32, row: u32) -> Option<usize> {
+        if column >= self.columns || row >= self.rows {
+            return None;
+        }
+        Some(row as usize * self.columns as usize + column as usize)
+    }
+}
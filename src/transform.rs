@@ -0,0 +1,116 @@
+/// A 2d affine transform, which can rotate, scale, skew and translate points.
+///
+/// A point (x, y) is transformed to (a * x + c * y + e, b * x + d * y + f), the same layout used by SVG and the
+/// HTML canvas. Transforms are combined with [`Transform::then`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Transform {
+    /// How much x contributes to the new x.
+    pub a: f64,
+    /// How much x contributes to the new y.
+    pub b: f64,
+    /// How much y contributes to the new x.
+    pub c: f64,
+    /// How much y contributes to the new y.
+    pub d: f64,
+    /// How far the new x is moved.
+    pub e: f64,
+    /// How far the new y is moved.
+    pub f: f64,
+}
+
+impl Default for Transform {
+    fn default() -> Self {
+        Self::identity()
+    }
+}
+
+impl Transform {
+    /// A transform that leaves every point where it is.
+    pub fn identity() -> Self {
+        Self {
+            a: 1.0,
+            b: 0.0,
+            c: 0.0,
+            d: 1.0,
+            e: 0.0,
+            f: 0.0,
+        }
+    }
+
+    /// Moves points by (x, y).
+    pub fn translate(x: f64, y: f64) -> Self {
+        Self {
+            e: x,
+            f: y,
+            ..Self::identity()
+        }
+    }
+
+    /// Scales points away from the origin.
+    pub fn scale(x: f64, y: f64) -> Self {
+        Self {
+            a: x,
+            d: y,
+            ..Self::identity()
+        }
+    }
+
+    /// Rotates points around the origin by `angle` radians. Because y=0 starts at the top of the frame,
+    /// positive angles rotate clockwise.
+    pub fn rotate(angle: f64) -> Self {
+        let (sin, cos) = angle.sin_cos();
+        Self {
+            a: cos,
+            b: sin,
+            c: -sin,
+            d: cos,
+            ..Self::identity()
+        }
+    }
+
+    /// Skews points by `x` radians along the x axis and `y` radians along the y axis.
+    pub fn skew(x: f64, y: f64) -> Self {
+        Self {
+            b: y.tan(),
+            c: x.tan(),
+            ..Self::identity()
+        }
+    }
+
+    /// A transform that applies this transform first, and `next` after it.
+    pub fn then(&self, next: &Transform) -> Transform {
+        Transform {
+            a: next.a * self.a + next.c * self.b,
+            b: next.b * self.a + next.d * self.b,
+            c: next.a * self.c + next.c * self.d,
+            d: next.b * self.c + next.d * self.d,
+            e: next.a * self.e + next.c * self.f + next.e,
+            f: next.b * self.e + next.d * self.f + next.f,
+        }
+    }
+
+    /// The transform that undoes this one, or `None` if it squashes everything onto a line or a point.
+    pub fn invert(&self) -> Option<Transform> {
+        let determinant = self.a * self.d - self.b * self.c;
+        if determinant.abs() < f64::EPSILON || !determinant.is_finite() {
+            return None;
+        }
+
+        Some(Transform {
+            a: self.d / determinant,
+            b: -self.b / determinant,
+            c: -self.c / determinant,
+            d: self.a / determinant,
+            e: (self.c * self.f - self.d * self.e) / determinant,
+            f: (self.b * self.e - self.a * self.f) / determinant,
+        })
+    }
+
+    /// Transforms a single point.
+    pub fn apply(&self, x: f64, y: f64) -> (f64, f64) {
+        (
+            self.a * x + self.c * y + self.e,
+            self.b * x + self.d * y + self.f,
+        )
+    }
+}